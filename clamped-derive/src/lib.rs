@@ -0,0 +1,141 @@
+//! Derive bounded construction for newtypes over integers.
+//!
+//! ```
+//! # use clamped_derive::Clamped;
+//! #[derive(Clamped)]
+//! #[clamped(range = "0..150")]
+//! struct Age(u8);
+//!
+//! assert_eq!(Age::try_from(42).unwrap().get(), 42);
+//! assert!(Age::try_from(200).is_err());
+//! ```
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned as _, DeriveInput, Expr, ExprRange, RangeLimits};
+
+/// Implement `TryFrom<inner>` and `get` for a newtype over an integer.
+///
+/// The range is given as a string, using any of the range kinds, e.g.
+/// `#[clamped(range = "0..150")]`, `#[clamped(range = "-10..=10")]` or `#[clamped(range = "1..")]`.
+///
+/// Conversion errors are the same as for the corresponding `clamped` type.
+#[proc_macro_derive(Clamped, attributes(clamped))]
+pub fn derive_clamped(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let inner = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(fields),
+            ..
+        }) if fields.unnamed.len() == 1 => fields.unnamed[0].ty.clone(),
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "#[derive(Clamped)] only supports tuple structs with a single field",
+            ))
+        }
+    };
+    let prefix = match &inner {
+        syn::Type::Path(path) => path
+            .path
+            .get_ident()
+            .and_then(|ident| prefix(&ident.to_string())),
+        _ => None,
+    }
+    .ok_or_else(|| syn::Error::new(inner.span(), "expected a primitive integer type"))?;
+
+    let mut range = None;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("clamped") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("range") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                range = Some(lit.parse::<ExprRange>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported attribute"))
+            }
+        })?;
+    }
+    let range = range.ok_or_else(|| {
+        syn::Error::new(
+            Span::call_site(),
+            "missing #[clamped(range = \"..\")] attribute",
+        )
+    })?;
+
+    let arg = |expr: &Expr| quote!({ #expr });
+    let clamped = match (&range.start, &range.limits, &range.end) {
+        (Some(lower), RangeLimits::HalfOpen(_), Some(upper)) => {
+            let (lower, upper) = (arg(lower), arg(upper));
+            let ident = syn::Ident::new(&format!("Clamped{prefix}"), Span::call_site());
+            quote!(::clamped::#ident<#lower, #upper>)
+        }
+        (Some(lower), RangeLimits::HalfOpen(_), None) => {
+            let lower = arg(lower);
+            let ident = syn::Ident::new(&format!("Clamped{prefix}From"), Span::call_site());
+            quote!(::clamped::#ident<#lower>)
+        }
+        (Some(lower), RangeLimits::Closed(_), Some(upper)) => {
+            let (lower, upper) = (arg(lower), arg(upper));
+            let ident = syn::Ident::new(&format!("Clamped{prefix}Inclusive"), Span::call_site());
+            quote!(::clamped::#ident<#lower, #upper>)
+        }
+        (None, RangeLimits::HalfOpen(_), Some(upper)) => {
+            let upper = arg(upper);
+            let ident = syn::Ident::new(&format!("Clamped{prefix}To"), Span::call_site());
+            quote!(::clamped::#ident<#upper>)
+        }
+        (None, RangeLimits::Closed(_), Some(upper)) => {
+            let upper = arg(upper);
+            let ident = syn::Ident::new(&format!("Clamped{prefix}ToInclusive"), Span::call_site());
+            quote!(::clamped::#ident<#upper>)
+        }
+        _ => return Err(syn::Error::new(range.span(), "the range must be bounded")),
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::convert::TryFrom<#inner> for #ident #ty_generics #where_clause {
+            type Error = <#clamped as ::core::convert::TryFrom<#inner>>::Error;
+            fn try_from(inner: #inner) -> ::core::result::Result<Self, Self::Error> {
+                <#clamped as ::core::convert::TryFrom<#inner>>::try_from(inner).map(|_| Self(inner))
+            }
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// The inner integer.
+            pub fn get(&self) -> #inner {
+                self.0
+            }
+        }
+    })
+}
+
+/// The name of the `clamped` types for the given integer, e.g. `U8` for `ClampedU8`.
+fn prefix(inner: &str) -> Option<&'static str> {
+    Some(match inner {
+        "u8" => "U8",
+        "u16" => "U16",
+        "u32" => "U32",
+        "u64" => "U64",
+        "u128" => "U128",
+        "usize" => "Usize",
+        "i8" => "I8",
+        "i16" => "I16",
+        "i32" => "I32",
+        "i64" => "I64",
+        "i128" => "I128",
+        "isize" => "Isize",
+        _ => return None,
+    })
+}
@@ -0,0 +1,26 @@
+use clamped::{Clamped, OutOfBounds};
+
+#[derive(Clamped)]
+#[clamped(range = "0..150")]
+struct Age(u8);
+
+#[derive(Clamped)]
+#[clamped(range = "-10..=10")]
+struct Offset(i8);
+
+#[derive(Clamped)]
+#[clamped(range = "1..")]
+struct Threads(usize);
+
+#[test]
+fn derive() {
+    assert_eq!(Age::try_from(42).unwrap().get(), 42);
+    let _: OutOfBounds<u8> = Age::try_from(150).err().unwrap();
+
+    assert_eq!(Offset::try_from(-10).unwrap().get(), -10);
+    assert_eq!(Offset::try_from(10).unwrap().get(), 10);
+    assert!(Offset::try_from(11).is_err());
+
+    assert!(Threads::try_from(0).is_err());
+    assert_eq!(Threads::try_from(1).unwrap().get(), 1);
+}
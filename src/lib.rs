@@ -1,10 +1,40 @@
 //! Bounded integers.
+//!
+//! The inner value is available with `get`, which can be matched against ranges:
+//! ```
+//! # use clamped::ClampedU8Inclusive;
+//! fn describe(level: ClampedU8Inclusive<0, 10>) -> &'static str {
+//!     match level.get() {
+//!         0 => "off",
+//!         1..=5 => "low",
+//!         _ => "high",
+//!     }
+//! }
+//!
+//! assert_eq!(describe(ClampedU8Inclusive::new(3).unwrap()), "low");
+//! assert_eq!(describe(ClampedU8Inclusive::new(10).unwrap()), "high");
+//! ```
+//!
+//! Arithmetic that may leave the range returns a [`Result`]:
+//! ```
+//! # use clamped::ClampedU8;
+//! let percent = ClampedU8::<0, 100>::new(40).unwrap();
+//! assert_eq!((percent * 2).unwrap(), 80);
+//!
+//! let percent = ClampedU8::<0, 100>::new(60).unwrap();
+//! assert!((percent * 2).is_err());
+//! ```
 // names follow std::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive}.
 
-use std::{any, fmt};
+#[cfg(feature = "derive")]
+pub use clamped_derive::Clamped;
+
+#[cfg(not(feature = "precise-debug"))]
+use std::any;
+use std::{cmp, collections, fmt, iter, marker::PhantomData, mem, num, ops, str, time};
 
 /// Conversion error.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
 #[error("The value {given:?} is not in the half-open range {lower:?}..{upper:?}")]
 pub struct OutOfBounds<T: fmt::Debug> {
     lower: T,
@@ -13,7 +43,7 @@ pub struct OutOfBounds<T: fmt::Debug> {
 }
 
 /// Conversion error.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
 #[error("The value {given:?} is not in the inclusive range {lower:?}..")]
 pub struct OutOfBoundsFrom<T: fmt::Debug> {
     lower: T,
@@ -21,7 +51,7 @@ pub struct OutOfBoundsFrom<T: fmt::Debug> {
 }
 
 /// Conversion error.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
 #[error("The value {given:?} is not in the inclusive range {lower:?}..={upper:?}")]
 pub struct OutOfBoundsInclusive<T: fmt::Debug> {
     lower: T,
@@ -30,7 +60,7 @@ pub struct OutOfBoundsInclusive<T: fmt::Debug> {
 }
 
 /// Conversion error.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
 #[error("The value {given:?} is not in the exclusive range ..{upper:?}")]
 pub struct OutOfBoundsTo<T: fmt::Debug> {
     upper: T,
@@ -38,16 +68,423 @@ pub struct OutOfBoundsTo<T: fmt::Debug> {
 }
 
 /// Conversion error.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
 #[error("The value {given:?} is not in the inclusive range ..={upper:?}")]
 pub struct OutOfBoundsToInclusive<T: fmt::Debug> {
     upper: T,
     given: T,
 }
 
+/// Conversion error, naming the field being converted.
+#[derive(Debug, thiserror::Error)]
+#[error("field `{field}`: {source}")]
+pub struct NamedOutOfBounds<E> {
+    field: &'static str,
+    source: E,
+}
+
+/// Error parsing a clamped integer from a string.
+#[derive(Debug, thiserror::Error)]
+pub enum ParseClampedError<E> {
+    #[error("Could not parse the integer")]
+    Parse(#[source] num::ParseIntError),
+    #[error("The parsed integer is out of bounds")]
+    OutOfBounds(#[source] E),
+}
+
+/// Error decoding a clamped integer from bytes.
+#[derive(Debug, thiserror::Error)]
+pub enum DecodeError<E> {
+    #[error("Expected at least {needed} bytes, but got {given}")]
+    InsufficientBytes { needed: usize, given: usize },
+    #[error(transparent)]
+    OutOfBounds(E),
+}
+
+/// Error converting a slice into an array of clamped integers.
+#[derive(Debug, thiserror::Error)]
+pub enum ArrayConvertError<E> {
+    #[error("Expected {expected} elements, but got {given}")]
+    Length { expected: usize, given: usize },
+    #[error("The element at index {index} is out of bounds")]
+    OutOfBounds {
+        index: usize,
+        #[source]
+        source: E,
+    },
+}
+
+/// Either an upstream error, or a conversion error.
+#[derive(Debug, thiserror::Error)]
+pub enum ClampOrError<E, B> {
+    #[error(transparent)]
+    Upstream(E),
+    #[error(transparent)]
+    OutOfBounds(B),
+}
+
+/// Conversion error from a wider integer type.
+#[derive(Debug, thiserror::Error)]
+pub enum NarrowError<E> {
+    #[error(transparent)]
+    DoesNotFit(num::TryFromIntError),
+    #[error(transparent)]
+    OutOfBounds(E),
+}
+
+/// Error from arithmetic on a clamped integer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum ArithmeticError<T: fmt::Debug> {
+    #[error("The result of the operation on {given:?} overflows the inner type")]
+    Overflow { given: T },
+    #[error(transparent)]
+    OutOfBounds(OutOfBounds<T>),
+}
+
+/// Error dividing a clamped integer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum DivError<T: fmt::Debug> {
+    #[error("attempt to divide by zero")]
+    DivideByZero,
+    #[error("The result of dividing {given:?} overflows the inner type")]
+    Overflow { given: T },
+    #[error(transparent)]
+    OutOfBounds(OutOfBounds<T>),
+}
+
+/// Conversion error from a [`char`].
+#[derive(Debug, thiserror::Error)]
+pub enum FromAsciiError<E> {
+    #[error("The character {0:?} is not ASCII")]
+    NotAscii(char),
+    #[error(transparent)]
+    OutOfBounds(E),
+}
+
+/// Conversion error from a [`char`] to a byte.
+#[derive(Debug, thiserror::Error)]
+pub enum FromCharError<E> {
+    #[error("The character {0:?} is not in Latin-1")]
+    NotLatin1(char),
+    #[error(transparent)]
+    OutOfBounds(E),
+}
+
+/// Infallible conversion, pinning values outside the range to the nearest bound.
+pub trait SaturatingFrom<T> {
+    fn saturating_from(inner: T) -> Self;
+}
+
+/// Infallible conversion, pinning values outside the range to the nearest bound.
+///
+/// Prefer implementing [`SaturatingFrom`], which gives an implementation of this trait.
+pub trait SaturatingInto<T> {
+    fn saturating_into(self) -> T;
+}
+
+impl<T, U: SaturatingFrom<T>> SaturatingInto<U> for T {
+    fn saturating_into(self) -> U {
+        U::saturating_from(self)
+    }
+}
+
+/// A type bounded above and below, whose values are numbered by their offset from the lowest value.
+pub trait Bounded: Copy {
+    /// The fewest bits that can represent every offset.
+    const NEEDED_BITS: u32;
+    /// The distance of this value from the lowest value in the range.
+    fn to_offset(self) -> u128;
+    /// The value at `offset` from the lowest value, or [`None`] if that is out of bounds.
+    fn from_offset(offset: u128) -> Option<Self>;
+}
+
+/// Lazily convert the items of an iterator into clamped integers.
+pub trait ClampedIteratorExt: Iterator + Sized {
+    /// Convert each item with [`SaturatingFrom`].
+    fn clamped_saturating<C: SaturatingFrom<Self::Item>>(self) -> impl Iterator<Item = C> {
+        self.map(C::saturating_from)
+    }
+    /// Convert each item with [`TryFrom`].
+    fn clamped_try<C: TryFrom<Self::Item>>(self) -> impl Iterator<Item = Result<C, C::Error>> {
+        self.map(C::try_from)
+    }
+}
+
+impl<I: Iterator> ClampedIteratorExt for I {}
+
+/// Convert the contents of an [`Option`] into a clamped integer.
+pub trait ClampedOptionExt<T> {
+    /// Convert with [`TryFrom`], returning [`None`] if it fails.
+    fn clamp_opt<C: TryFrom<T>>(self) -> Option<C>;
+}
+
+impl<T> ClampedOptionExt<T> for Option<T> {
+    fn clamp_opt<C: TryFrom<T>>(self) -> Option<C> {
+        C::try_from(self?).ok()
+    }
+}
+
+/// Convert the contents of a [`Result`] into a clamped integer.
+pub trait ClampedResultExt<T, E> {
+    /// Convert with [`TryFrom`].
+    fn clamp_res<C: TryFrom<T>>(self) -> Result<C, ClampOrError<E, C::Error>>;
+}
+
+impl<T, E> ClampedResultExt<T, E> for Result<T, E> {
+    fn clamp_res<C: TryFrom<T>>(self) -> Result<C, ClampOrError<E, C::Error>> {
+        C::try_from(self.map_err(ClampOrError::Upstream)?).map_err(ClampOrError::OutOfBounds)
+    }
+}
+
+/// Convert integers into clamped integers as they are pushed onto a [`Vec`].
+pub trait ClampedVecExt<C> {
+    /// Convert with [`TryFrom`], and push if it succeeds.
+    fn push_raw<T>(&mut self, value: T) -> Result<(), <C as TryFrom<T>>::Error>
+    where
+        C: TryFrom<T>;
+    /// Convert with [`SaturatingFrom`], and push.
+    fn push_saturating<T>(&mut self, value: T)
+    where
+        C: SaturatingFrom<T>;
+}
+
+impl<C> ClampedVecExt<C> for Vec<C> {
+    fn push_raw<T>(&mut self, value: T) -> Result<(), <C as TryFrom<T>>::Error>
+    where
+        C: TryFrom<T>,
+    {
+        self.push(C::try_from(value)?);
+        Ok(())
+    }
+    fn push_saturating<T>(&mut self, value: T)
+    where
+        C: SaturatingFrom<T>,
+    {
+        self.push(C::saturating_from(value))
+    }
+}
+
+/// Cyclic arithmetic on a clamped integer with operators, like [`std::num::Wrapping`].
+///
+/// `+` and `-` with the inner integer wrap around the range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Wrapping<C>(pub C);
+
+impl<C> From<C> for Wrapping<C> {
+    fn from(clamped: C) -> Self {
+        Self(clamped)
+    }
+}
+
+impl<C> ops::Deref for Wrapping<C> {
+    type Target = C;
+    fn deref(&self) -> &C {
+        &self.0
+    }
+}
+
+/// An iterator over the values of a clamped integer, in ascending order.
+#[derive(Debug, Clone)]
+pub struct Iter<T> {
+    // The remaining values, inclusive, or None if exhausted.
+    remaining: Option<(T, T)>,
+}
+
+mod sealed {
+    pub trait Step: Copy + PartialEq {
+        fn succ(self) -> Self;
+        fn pred(self) -> Self;
+        /// [`None`] if it doesn't fit in a [`usize`].
+        fn distance(self, to: Self) -> Option<usize>;
+    }
+}
+
+impl<T: sealed::Step> Iterator for Iter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (front, back) = self.remaining?;
+        self.remaining = match front == back {
+            true => None,
+            false => Some((front.succ(), back)),
+        };
+        Some(front)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some((front, back)) => match front.distance(back).and_then(|it| it.checked_add(1)) {
+                Some(len) => (len, Some(len)),
+                None => (usize::MAX, None),
+            },
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<T: sealed::Step> DoubleEndedIterator for Iter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (front, back) = self.remaining?;
+        self.remaining = match front == back {
+            true => None,
+            false => Some((front, back.pred())),
+        };
+        Some(back)
+    }
+}
+
+impl<T: sealed::Step> iter::FusedIterator for Iter<T> {}
+
+/// Deserializes an integer, or a string containing one, in human-readable formats.
+#[cfg(feature = "serde")]
+struct LenientVisitor<T>(PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<T> LenientVisitor<T> {
+    fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: serde::Deserialize<'de>,
+        Self: serde::de::Visitor<'de, Value = T>,
+    {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(Self(PhantomData)),
+            false => T::deserialize(deserializer),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::de::Visitor<'de> for LenientVisitor<T>
+where
+    T: TryFrom<u64> + TryFrom<i64> + TryFrom<u128> + TryFrom<i128> + str::FromStr,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an integer, or a string containing an integer")
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<T, E> {
+        T::try_from(v).map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<T, E> {
+        T::try_from(v).map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<T, E> {
+        T::try_from(v).map_err(|_| E::custom(format_args!("integer `{v}` is out of range")))
+    }
+
+    fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<T, E> {
+        T::try_from(v).map_err(|_| E::custom(format_args!("integer `{v}` is out of range")))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+    }
+}
+
+/// Sample the triangular distribution over `low..=high`, peaked at `mode`.
+#[cfg(feature = "rand")]
+fn triangular<R: rand::Rng + ?Sized>(rng: &mut R, low: f64, high: f64, mode: f64) -> f64 {
+    if low == high {
+        return low;
+    }
+    // invert the cumulative distribution function
+    let u = rng.gen::<f64>();
+    match u < (mode - low) / (high - low) {
+        true => low + (u * (high - low) * (mode - low)).sqrt(),
+        false => high - ((1.0 - u) * (high - low) * (high - mode)).sqrt(),
+    }
+}
+
+/// [`num::ParseIntError`] has no public constructor, so get one by parsing `invalid`.
+fn parse_int_error<T: str::FromStr<Err = num::ParseIntError>>(invalid: &str) -> num::ParseIntError {
+    match invalid.parse::<T>() {
+        Ok(_) => unreachable!("{invalid:?} should not parse"),
+        Err(e) => e,
+    }
+}
+
+/// Parse base-10 `digits`, each in `0..=9`, most significant first.
+fn parse_digits<T: str::FromStr<Err = num::ParseIntError>>(
+    digits: impl IntoIterator<Item = u8>,
+) -> Result<T, num::ParseIntError> {
+    let mut src = String::new();
+    for digit in digits {
+        if digit > 9 {
+            return Err(parse_int_error::<u8>("x"));
+        }
+        src.push(char::from(b'0' + digit));
+    }
+    src.parse()
+}
+
+/// Parse with `from_str_radix`, detecting a `0x`, `0o` or `0b` prefix after any sign.
+///
+/// Defaults to decimal.
+fn parse_auto_radix<T>(
+    src: &str,
+    from_str_radix: fn(&str, u32) -> Result<T, num::ParseIntError>,
+) -> Result<T, num::ParseIntError> {
+    let (negative, unsigned) = match src.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, src.strip_prefix('+').unwrap_or(src)),
+    };
+    let (radix, digits) = match unsigned.get(..2) {
+        Some("0x" | "0X") => (16, &unsigned[2..]),
+        Some("0o" | "0O") => (8, &unsigned[2..]),
+        Some("0b" | "0B") => (2, &unsigned[2..]),
+        _ => (10, unsigned),
+    };
+    if digits.starts_with(['+', '-']) {
+        return Err(parse_int_error::<u8>("x"));
+    }
+    match negative {
+        true => from_str_radix(&format!("-{digits}"), radix),
+        false => from_str_radix(digits, radix),
+    }
+}
+
+/// Insert `sep` between each group of three `digits`, after any sign.
+#[cfg(feature = "format")]
+fn group_digits(digits: &str, sep: char) -> String {
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", digits),
+    };
+    let mut grouped = String::from(sign);
+    for (ix, digit) in digits.chars().enumerate() {
+        if ix != 0 && (digits.len() - ix) % 3 == 0 {
+            grouped.push(sep);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Implement [`PartialEq`] and [`PartialOrd`] between two range kinds, comparing the inner integers.
+macro_rules! cross_kind_cmp {
+    ([$($lhs_params:tt)*] $lhs:ty, [$($rhs_params:tt)*] $rhs:ty) => {
+        impl<$($lhs_params)*, $($rhs_params)*> PartialEq<$rhs> for $lhs {
+            fn eq(&self, other: &$rhs) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<$($lhs_params)*, $($rhs_params)*> PartialOrd<$rhs> for $lhs {
+            fn partial_cmp(&self, other: &$rhs) -> Option<cmp::Ordering> {
+                Some(self.0.cmp(&other.0))
+            }
+        }
+    };
+}
+
 macro_rules! clamped {
     (
         $inner:ty,
+        $unsigned:ty,
         $clamped:ident,
         $clamped_from:ident,
         $clamped_inclusive:ident,
@@ -55,205 +492,675 @@ macro_rules! clamped {
         $clamped_to_inclusive:ident $(,)?
     ) => {
         /// An integer bound in the half-open range inclusively below and exclusively above `LOWER..UPPER`.
-        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
         #[repr(transparent)]
         pub struct $clamped<const LOWER: $inner, const UPPER: $inner>($inner);
 
-        impl<const LOWER: $inner, const UPPER: $inner> TryFrom<$inner> for $clamped<LOWER, UPPER> {
-            type Error = OutOfBounds<$inner>;
-            /// # Panics
-            /// In debug mode if `!(LOWER < UPPER)`
-            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
-                debug_assert!(LOWER < UPPER);
-                if inner < LOWER || inner >= UPPER {
-                    Err(OutOfBounds {
-                        lower: LOWER,
-                        upper: UPPER,
-                        given: inner,
-                    })
-                } else {
-                    Ok(Self(inner))
-                }
+        impl<const LOWER: $inner, const UPPER: $inner> $clamped<LOWER, UPPER> {
+            /// The inner integer.
+            pub const fn get(self) -> $inner {
+                self.0
             }
-        }
 
-        impl<const LOWER: $inner, const UPPER: $inner> From<$clamped<LOWER, UPPER>> for $inner {
-            fn from(clamped: $clamped<LOWER, UPPER>) -> $inner {
-                clamped.0
+            /// The inner integer, identical to [`Self::get`].
+            pub const fn as_inner_copy(self) -> $inner {
+                self.0
             }
-        }
 
-        impl<const LOWER: $inner, const UPPER: $inner> fmt::Debug for $clamped<LOWER, UPPER> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                // #[derive(Debug)] doesn't preserve the const type parameters
-                // Use type_name instead, and strip the module path
-                let name = match any::type_name::<Self>().rsplit_once("::") {
-                    Some((_pre, post)) => post,
-                    None => any::type_name::<Self>(),
-                };
-                f.debug_tuple(name).field(&self.0).finish()
+            /// Construct, performing the same validation as [`TryFrom`].
+            pub fn new(inner: $inner) -> Result<Self, <Self as TryFrom<$inner>>::Error> {
+                Self::try_from(inner)
             }
-        }
 
-        impl<const LOWER: $inner, const UPPER: $inner> PartialEq<$inner>
-            for $clamped<LOWER, UPPER>
-        {
-            fn eq(&self, other: &$inner) -> bool {
-                self.0 == *other
+            /// Construct, naming the field in the error on failure.
+            pub fn try_new_named(
+                inner: $inner,
+                field: &'static str,
+            ) -> Result<Self, NamedOutOfBounds<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(inner).map_err(|source| NamedOutOfBounds { field, source })
             }
-        }
 
-        /// An integer only bounded inclusively below `LOWER..`.
-        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-        #[repr(transparent)]
-        pub struct $clamped_from<const LOWER: $inner>($inner);
+            /// [`PartialEq::eq`], usable in `const` contexts.
+            pub const fn const_eq(self, other: Self) -> bool {
+                self.0 == other.0
+            }
 
-        impl<const LOWER: $inner> TryFrom<$inner> for $clamped_from<LOWER> {
-            type Error = OutOfBoundsFrom<$inner>;
-            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
-                if inner < LOWER {
-                    Err(OutOfBoundsFrom {
-                        lower: LOWER,
-                        given: inner,
-                    })
+            /// [`Ord::cmp`], usable in `const` contexts.
+            pub const fn const_cmp(self, other: Self) -> cmp::Ordering {
+                if self.0 < other.0 {
+                    cmp::Ordering::Less
+                } else if self.0 > other.0 {
+                    cmp::Ordering::Greater
                 } else {
-                    Ok(Self(inner))
+                    cmp::Ordering::Equal
                 }
             }
-        }
 
-        impl<const LOWER: $inner> From<$clamped_from<LOWER>> for $inner {
-            fn from(clamped: $clamped_from<LOWER>) -> $inner {
-                clamped.0
+            /// Construct, pinning values outside the range to the nearest bound.
+            ///
+            /// Usable in `const` contexts, to build tables of valid values from raw constants.
+            ///
+            /// Fails to compile if the range is empty.
+            pub const fn clamp_const(inner: $inner) -> Self {
+                const { assert!(LOWER < UPPER, "the range is empty") };
+                if inner < LOWER {
+                    Self(LOWER)
+                } else if inner >= UPPER {
+                    Self(UPPER - 1)
+                } else {
+                    Self(inner)
+                }
             }
-        }
 
-        impl<const LOWER: $inner> fmt::Debug for $clamped_from<LOWER> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                // #[derive(Debug)] doesn't preserve the const type parameters
-                // Use type_name instead, and strip the module path
-                let name = match any::type_name::<Self>().rsplit_once("::") {
-                    Some((_pre, post)) => post,
-                    None => any::type_name::<Self>(),
-                };
-                f.debug_tuple(name).field(&self.0).finish()
+            /// Construct, pinning values outside the range into it, as [`Self::clamp_const`].
+            ///
+            /// Fails to compile if the range is empty, since there is no value to pin to.
+            pub const fn saturating_from(inner: $inner) -> Self {
+                Self::clamp_const(inner)
             }
-        }
 
-        impl<const LOWER: $inner> PartialEq<$inner> for $clamped_from<LOWER> {
-            fn eq(&self, other: &$inner) -> bool {
-                self.0 == *other
+            /// The number of values in the range.
+            pub const CARDINALITY: $unsigned = UPPER.wrapping_sub(LOWER) as $unsigned;
+
+            /// The distance of this value from `LOWER`, in `0..CARDINALITY`.
+            pub const fn offset(self) -> $unsigned {
+                self.0.wrapping_sub(LOWER) as $unsigned
             }
-        }
 
-        /// An integer bound in the inclusive range below and above `LOWER..=UPPER`.
-        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-        #[repr(transparent)]
-        pub struct $clamped_inclusive<const LOWER: $inner, const UPPER: $inner>($inner);
+            /// Count the occurrences of each value, indexed by [`Self::offset`].
+            ///
+            /// `N` must be [`Self::CARDINALITY`], or this will fail to compile.
+            pub fn histogram<const N: usize>(values: impl IntoIterator<Item = Self>) -> [usize; N] {
+                const { assert!(N as u128 == Self::CARDINALITY as u128) };
+                let mut counts = [0; N];
+                for value in values {
+                    counts[value.offset() as usize] += 1;
+                }
+                counts
+            }
 
-        impl<const LOWER: $inner, const UPPER: $inner> TryFrom<$inner>
-            for $clamped_inclusive<LOWER, UPPER>
-        {
-            type Error = OutOfBoundsInclusive<$inner>;
-            /// # Panics
-            /// In debug mode if `!(LOWER <= UPPER)`
-            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
-                debug_assert!(LOWER <= UPPER);
-                if inner < LOWER || inner > UPPER {
-                    Err(OutOfBoundsInclusive {
-                        lower: LOWER,
-                        upper: UPPER,
-                        given: inner,
-                    })
-                } else {
-                    Ok(Self(inner))
+            /// Step one closer to `target` (pinned into the range), for shrinking test cases.
+            ///
+            /// Returns [`None`] if already at `target`.
+            pub fn shrink_toward(self, target: $inner) -> Option<Self> {
+                let target = Self::saturating_from(target).0;
+                // `target` is in the range, so every value between it and `self` is too
+                match self.0.cmp(&target) {
+                    cmp::Ordering::Greater => Some(Self(self.0 - 1)),
+                    cmp::Ordering::Less => Some(Self(self.0 + 1)),
+                    cmp::Ordering::Equal => None,
                 }
             }
-        }
 
-        impl<const LOWER: $inner, const UPPER: $inner> From<$clamped_inclusive<LOWER, UPPER>>
-            for $inner
-        {
-            fn from(clamped: $clamped_inclusive<LOWER, UPPER>) -> $inner {
-                clamped.0
+            /// Collect `values` into a sorted [`Vec`].
+            pub fn sorted(values: impl IntoIterator<Item = Self>) -> Vec<Self> {
+                let mut values = values.into_iter().collect::<Vec<_>>();
+                values.sort_unstable();
+                values
             }
-        }
 
-        impl<const LOWER: $inner, const UPPER: $inner> fmt::Debug
-            for $clamped_inclusive<LOWER, UPPER>
-        {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                // #[derive(Debug)] doesn't preserve the const type parameters
-                // Use type_name instead, and strip the module path
-                let name = match any::type_name::<Self>().rsplit_once("::") {
-                    Some((_pre, post)) => post,
-                    None => any::type_name::<Self>(),
-                };
-                f.debug_tuple(name).field(&self.0).finish()
+            /// The width of the inner integer, in bits.
+            pub const BITS: u32 = <$inner>::BITS;
+
+            /// The fewest bits that can represent every [`Self::offset`].
+            pub const NEEDED_BITS: u32 =
+                <$unsigned>::BITS - Self::CARDINALITY.saturating_sub(1).leading_zeros();
+
+            /// Decode from the little-endian bytes at the front of `bytes`.
+            pub fn try_from_le_slice(
+                bytes: &[u8],
+            ) -> Result<Self, DecodeError<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(<$inner>::from_le_bytes(Self::front(bytes)?))
+                    .map_err(DecodeError::OutOfBounds)
             }
-        }
 
-        impl<const LOWER: $inner, const UPPER: $inner> PartialEq<$inner>
-            for $clamped_inclusive<LOWER, UPPER>
-        {
-            fn eq(&self, other: &$inner) -> bool {
-                self.0 == *other
+            /// Decode from the big-endian bytes at the front of `bytes`.
+            pub fn try_from_be_slice(
+                bytes: &[u8],
+            ) -> Result<Self, DecodeError<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(<$inner>::from_be_bytes(Self::front(bytes)?))
+                    .map_err(DecodeError::OutOfBounds)
             }
-        }
 
-        /// An integer bound in the exclusive range above `..UPPER`.
-        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-        #[repr(transparent)]
-        pub struct $clamped_to<const UPPER: $inner>($inner);
+            fn front(
+                bytes: &[u8],
+            ) -> Result<[u8; mem::size_of::<$inner>()], DecodeError<<Self as TryFrom<$inner>>::Error>>
+            {
+                match bytes.first_chunk() {
+                    Some(front) => Ok(*front),
+                    None => Err(DecodeError::InsufficientBytes {
+                        needed: mem::size_of::<$inner>(),
+                        given: bytes.len(),
+                    }),
+                }
+            }
 
-        impl<const UPPER: $inner> TryFrom<$inner> for $clamped_to<UPPER> {
-            type Error = OutOfBoundsTo<$inner>;
-            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
-                if inner >= UPPER {
-                    Err(OutOfBoundsTo {
-                        upper: UPPER,
-                        given: inner,
-                    })
+            /// The bitwise complement, returning [`None`] if it is out of bounds.
+            pub fn checked_not(self) -> Option<Self> {
+                Self::try_from(!self.0).ok()
+            }
+
+            /// Convert a slice of exactly `N` elements, validating each one.
+            pub fn try_from_slice_array<const N: usize>(
+                inners: &[$inner],
+            ) -> Result<[Self; N], ArrayConvertError<<Self as TryFrom<$inner>>::Error>> {
+                let array = <[$inner; N]>::try_from(inners).map_err(|_| ArrayConvertError::Length {
+                    expected: N,
+                    given: inners.len(),
+                })?;
+                for (index, inner) in array.into_iter().enumerate() {
+                    Self::try_from(inner)
+                        .map_err(|source| ArrayConvertError::OutOfBounds { index, source })?;
+                }
+                Ok(array.map(Self))
+            }
+
+            /// Every value in the range, in ascending order.
+            pub fn iter() -> Iter<Self> {
+                Iter {
+                    remaining: match LOWER < UPPER {
+                        true => Some((Self(LOWER), Self(UPPER - 1))),
+                        false => None,
+                    },
+                }
+            }
+
+            /// The smallest of `values`, or [`None`] if there are none.
+            pub fn min_of(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+                values.into_iter().min()
+            }
+
+            /// The largest of `values`, or [`None`] if there are none.
+            pub fn max_of(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+                values.into_iter().max()
+            }
+
+            /// Step up by one, returning [`None`] at the top of the range.
+            pub fn increment(self) -> Option<Self> {
+                Self::try_from(self.0.checked_add(1)?).ok()
+            }
+
+            /// Step down by one, returning [`None`] at the bottom of the range.
+            pub fn decrement(self) -> Option<Self> {
+                Self::try_from(self.0.checked_sub(1)?).ok()
+            }
+
+            /// Whether `inner` is in the range.
+            pub const fn contains(inner: $inner) -> bool {
+                LOWER <= inner && inner < UPPER
+            }
+
+            /// Add `rhs`, wrapping around the range.
+            ///
+            /// # Panics
+            /// In debug mode if `!(LOWER < UPPER)`
+            pub fn wrapping_add(self, rhs: $inner) -> Self {
+                debug_assert!(LOWER < UPPER);
+                let cardinality = Self::CARDINALITY;
+                let (offset, rhs) = (self.offset(), Self::reduce_offset(rhs, cardinality));
+                let offset = match offset >= cardinality - rhs {
+                    true => offset - (cardinality - rhs),
+                    false => offset + rhs,
+                };
+                Self(LOWER.wrapping_add(offset as $inner))
+            }
+
+            /// Subtract `rhs`, wrapping around the range.
+            ///
+            /// # Panics
+            /// In debug mode if `!(LOWER < UPPER)`
+            pub fn wrapping_sub(self, rhs: $inner) -> Self {
+                debug_assert!(LOWER < UPPER);
+                let cardinality = Self::CARDINALITY;
+                let (offset, rhs) = (self.offset(), Self::reduce_offset(rhs, cardinality));
+                let offset = match offset >= rhs {
+                    true => offset - rhs,
+                    false => offset + (cardinality - rhs),
+                };
+                Self(LOWER.wrapping_add(offset as $inner))
+            }
+
+            /// Step up by one, wrapping from the top of the range to `LOWER`.
+            pub fn wrapping_increment(self) -> Self {
+                self.wrapping_add(1)
+            }
+
+            /// Step down by one, wrapping from `LOWER` to the top of the range.
+            pub fn wrapping_decrement(self) -> Self {
+                self.wrapping_sub(1)
+            }
+
+            /// `rhs` modulo `cardinality`, as an offset.
+            fn reduce_offset(rhs: $inner, cardinality: $unsigned) -> $unsigned {
+                // rhs == (rhs - MIN) + MIN, where (rhs - MIN) is never negative
+                let shift = (<$inner>::MIN as $unsigned) % cardinality;
+                let rhs = (rhs.wrapping_sub(<$inner>::MIN) as $unsigned) % cardinality;
+                match rhs >= shift {
+                    true => rhs - shift,
+                    false => rhs + (cardinality - shift),
+                }
+            }
+
+            /// Every value from `start` onwards, in ascending order.
+            pub fn iter_from(start: Self) -> Iter<Self> {
+                Iter {
+                    remaining: Self::iter().remaining.map(|(_, back)| (start, back)),
+                }
+            }
+
+            /// Every value before `end`, in ascending order.
+            pub fn iter_to(end: Self) -> Iter<Self> {
+                Iter {
+                    remaining: Self::iter().remaining.and_then(|(front, _)| match front == end {
+                        true => None,
+                        false => Some((front, Self(end.0 - 1))),
+                    }),
+                }
+            }
+
+            /// Convert every value, or report every value that is out of bounds.
+            pub fn validate_all(
+                inners: &[$inner],
+            ) -> Result<Vec<Self>, Vec<(usize, <Self as TryFrom<$inner>>::Error)>> {
+                let mut oks = Vec::with_capacity(inners.len());
+                let mut errs = Vec::new();
+                for (index, inner) in inners.iter().enumerate() {
+                    match Self::try_from(*inner) {
+                        Ok(ok) => oks.push(ok),
+                        Err(err) => errs.push((index, err)),
+                    }
+                }
+                match errs.is_empty() {
+                    true => Ok(oks),
+                    false => Err(errs),
+                }
+            }
+
+            /// Shift left, returning [`None`] on overflow or if the result is out of bounds.
+            pub fn checked_shl(self, rhs: u32) -> Option<Self> {
+                Self::new(self.0.checked_shl(rhs)?).ok()
+            }
+
+            /// Shift right, returning [`None`] on overflow or if the result is out of bounds.
+            pub fn checked_shr(self, rhs: u32) -> Option<Self> {
+                Self::new(self.0.checked_shr(rhs)?).ok()
+            }
+
+            /// Round to the nearest integer, pinning the result into the range.
+            ///
+            /// NaN is mapped to the lowest value in the range.
+            pub fn from_f64_saturating(value: f64) -> Self {
+                match value.is_nan() {
+                    true => Self::saturating_from(<$inner>::MIN),
+                    // `as` saturates at the limits of the inner type
+                    false => Self::saturating_from(value.round() as $inner),
+                }
+            }
+
+            /// Pin into `min..=max` as well as the range, mirroring [`Ord::clamp`].
+            ///
+            /// # Panics
+            /// In debug mode if `min > max`, or if no value in the range is in `min..=max`.
+            pub fn clamp_to(self, min: $inner, max: $inner) -> Self {
+                debug_assert!(min <= max);
+                let lowest = Self::clamp_const(min).0;
+                debug_assert!(
+                    min <= lowest && lowest <= max,
+                    "no value in range is in {min:?}..={max:?}"
+                );
+                Self::clamp_const(self.0.max(min).min(max))
+            }
+
+            /// Re-validate against new bounds.
+            pub fn checked_rebound<const L2: $inner, const U2: $inner>(
+                self,
+            ) -> Result<$clamped<L2, U2>, OutOfBounds<$inner>> {
+                $clamped::try_from(self.0)
+            }
+
+            /// Whether every value in this range is also in `L2..U2`,
+            /// so that [`Self::checked_rebound`] always succeeds.
+            pub const fn is_subrange_of<const L2: $inner, const U2: $inner>() -> bool {
+                L2 <= LOWER && UPPER <= U2
+            }
+
+            /// The inner integer, for use with e.g. [`slice::sort_by_key`].
+            pub const fn sort_key(self) -> $inner {
+                self.0
+            }
+
+            /// Accumulate base-10 `digits`, each in `0..=9`, most significant first.
+            ///
+            /// Errors are as for [`FromStr`](str::FromStr), e.g. for empty input, or digits out of range.
+            pub fn from_digits(
+                digits: impl IntoIterator<Item = u8>,
+            ) -> Result<Self, ParseClampedError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = parse_digits::<$inner>(digits).map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+
+            /// Every value in ascending order, repeated endlessly.
+            ///
+            /// Yields nothing if the range is empty.
+            pub fn cycle() -> impl Iterator<Item = Self> {
+                Self::iter().cycle()
+            }
+
+            /// Whether this range shares any values with `L2..U2`.
+            pub const fn overlaps_with<const L2: $inner, const U2: $inner>() -> bool {
+                LOWER < U2 && L2 < UPPER && LOWER < UPPER && L2 < U2
+            }
+
+            /// Round to the nearest integer, pinning the result into the range.
+            ///
+            /// NaN is mapped to the lowest value in the range.
+            pub fn from_f32_saturating(value: f32) -> Self {
+                match value.is_nan() {
+                    true => Self::saturating_from(<$inner>::MIN),
+                    // `as` saturates at the limits of the inner type
+                    false => Self::saturating_from(value.round() as $inner),
+                }
+            }
+
+            /// The bitwise OR, for ranges `0..2^k`, which always contain it.
+            ///
+            /// Fails to compile for other ranges.
+            pub const fn union_unchecked(self, other: Self) -> Self {
+                const { assert!(LOWER == 0 && UPPER > 0 && UPPER & (UPPER - 1) == 0) };
+                Self(self.0 | other.0)
+            }
+
+            /// The bitwise OR, returning [`None`] if it is out of bounds.
+            pub fn checked_union(self, other: Self) -> Option<Self> {
+                Self::new(self.0 | other.0).ok()
+            }
+
+            /// Re-validate against the inclusive range `LOWER..=U2`.
+            ///
+            /// `U2` should be `UPPER - 1` for the same values, otherwise this fails for values outside `LOWER..=U2`.
+            pub fn to_inclusive<const U2: $inner>(
+                self,
+            ) -> Result<$clamped_inclusive<LOWER, U2>, OutOfBoundsInclusive<$inner>> {
+                $clamped_inclusive::try_from(self.0)
+            }
+
+            /// Sample values clustered around `mode`, with a triangular distribution over the range.
+            ///
+            /// # Panics
+            /// If the range is empty.
+            #[cfg(feature = "rand")]
+            pub fn sample_triangular<R: rand::Rng + ?Sized>(rng: &mut R, mode: Self) -> Self {
+                assert!(LOWER < UPPER, "the range is empty");
+                let sample = triangular(rng, LOWER as f64, (UPPER - 1) as f64, mode.0 as f64);
+                Self::from_f64_saturating(sample)
+            }
+
+            /// Parse, detecting a `0x`, `0o` or `0b` prefix after any sign, and defaulting to decimal.
+            pub fn from_str_auto_radix(
+                src: &str,
+            ) -> Result<Self, ParseClampedError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = parse_auto_radix(src, <$inner>::from_str_radix)
+                    .map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+
+            /// Every entry of `map`, over the whole range.
+            pub fn range_query<V>(
+                map: &collections::BTreeMap<Self, V>,
+            ) -> collections::btree_map::Range<'_, Self, V> {
+                // every key is in the range, and this avoids constructing an inverted range if it is empty
+                map.range(..)
+            }
+
+            /// Render with `sep` between each group of three digits, e.g. `1,000,000`.
+            #[cfg(feature = "format")]
+            pub fn to_grouped_string(self, sep: char) -> String {
+                group_digits(&self.0.to_string(), sep)
+            }
+
+            /// Reflect across the middle of the range, so that the lowest value maps to the highest.
+            pub const fn mirror(self) -> Self {
+                // the true result is in the range, so wrapping can't lose anything
+                Self(LOWER.wrapping_add(UPPER - 1).wrapping_sub(self.0))
+            }
+
+            /// The value halfway between this and `other`, rounding toward this, without overflow.
+            pub const fn midpoint_with(self, other: Self) -> Self {
+                // both values are in the range, so anything between them is too
+                if self.0 <= other.0 {
+                    let half = (other.0.wrapping_sub(self.0) as $unsigned / 2) as $inner;
+                    Self(self.0.wrapping_add(half))
                 } else {
-                    Ok(Self(inner))
+                    let half = (self.0.wrapping_sub(other.0) as $unsigned / 2) as $inner;
+                    Self(self.0.wrapping_sub(half))
                 }
             }
         }
 
-        impl<const UPPER: $inner> From<$clamped_to<UPPER>> for $inner {
-            fn from(clamped: $clamped_to<UPPER>) -> $inner {
-                clamped.0
+        /// As for the inner integer.
+        impl<const LOWER: $inner, const UPPER: $inner> fmt::Display for $clamped<LOWER, UPPER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
             }
         }
 
-        impl<const UPPER: $inner> fmt::Debug for $clamped_to<UPPER> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                // #[derive(Debug)] doesn't preserve the const type parameters
-                // Use type_name instead, and strip the module path
-                let name = match any::type_name::<Self>().rsplit_once("::") {
-                    Some((_pre, post)) => post,
-                    None => any::type_name::<Self>(),
-                };
-                f.debug_tuple(name).field(&self.0).finish()
+        /// As for [`FromStr`](str::FromStr).
+        impl<'a, const LOWER: $inner, const UPPER: $inner> TryFrom<&'a str> for $clamped<LOWER, UPPER> {
+            type Error = ParseClampedError<<Self as TryFrom<$inner>>::Error>;
+            fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                s.parse()
             }
         }
 
-        impl<const UPPER: $inner> PartialEq<$inner> for $clamped_to<UPPER> {
-            fn eq(&self, other: &$inner) -> bool {
-                self.0 == *other
+        impl<const LOWER: $inner, const UPPER: $inner> From<Wrapping<$clamped<LOWER, UPPER>>> for $clamped<LOWER, UPPER> {
+            fn from(wrapping: Wrapping<$clamped<LOWER, UPPER>>) -> Self {
+                wrapping.0
             }
         }
 
-        /// An integer bound in the inclusive range above `..=UPPER`.
-        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-        #[repr(transparent)]
-        pub struct $clamped_to_inclusive<const UPPER: $inner>($inner);
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Add<$inner> for Wrapping<$clamped<LOWER, UPPER>> {
+            type Output = Self;
+            fn add(self, rhs: $inner) -> Self {
+                Wrapping(self.0.wrapping_add(rhs))
+            }
+        }
 
-        impl<const UPPER: $inner> TryFrom<$inner> for $clamped_to_inclusive<UPPER> {
-            type Error = OutOfBoundsTo<$inner>;
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Sub<$inner> for Wrapping<$clamped<LOWER, UPPER>> {
+            type Output = Self;
+            fn sub(self, rhs: $inner) -> Self {
+                Wrapping(self.0.wrapping_sub(rhs))
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> ops::AddAssign<$inner> for Wrapping<$clamped<LOWER, UPPER>> {
+            fn add_assign(&mut self, rhs: $inner) {
+                *self = *self + rhs
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> ops::SubAssign<$inner> for Wrapping<$clamped<LOWER, UPPER>> {
+            fn sub_assign(&mut self, rhs: $inner) {
+                *self = *self - rhs
+            }
+        }
+
+        /// Divide by the inner integer, checking that the quotient is in bounds.
+        ///
+        /// Dividing by zero is an error, rather than a panic.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Div<$inner> for $clamped<LOWER, UPPER> {
+            type Output = Result<Self, DivError<$inner>>;
+            fn div(self, rhs: $inner) -> Self::Output {
+                if rhs == 0 {
+                    return Err(DivError::DivideByZero);
+                }
+                match self.0.checked_div(rhs) {
+                    Some(quotient) => Self::try_from(quotient).map_err(DivError::OutOfBounds),
+                    // `MIN / -1`
+                    None => Err(DivError::Overflow { given: self.0 }),
+                }
+            }
+        }
+
+        /// Subtract the inner integer, checking that the difference is in bounds.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Sub<$inner> for $clamped<LOWER, UPPER> {
+            type Output = Result<Self, ArithmeticError<$inner>>;
+            fn sub(self, rhs: $inner) -> Self::Output {
+                match self.0.checked_sub(rhs) {
+                    Some(difference) => Self::try_from(difference).map_err(ArithmeticError::OutOfBounds),
+                    None => Err(ArithmeticError::Overflow { given: self.0 }),
+                }
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> Bounded for $clamped<LOWER, UPPER> {
+            const NEEDED_BITS: u32 = Self::NEEDED_BITS;
+            fn to_offset(self) -> u128 {
+                self.offset() as u128
+            }
+            fn from_offset(offset: u128) -> Option<Self> {
+                let offset = <$unsigned>::try_from(offset).ok()?;
+                match offset < Self::CARDINALITY {
+                    true => Some(Self(LOWER.wrapping_add(offset as $inner))),
+                    false => None,
+                }
+            }
+        }
+
+        /// Yields just this value.
+        ///
+        /// See [`Self::iter`] for every value in the range.
+        impl<const LOWER: $inner, const UPPER: $inner> IntoIterator for $clamped<LOWER, UPPER> {
+            type Item = Self;
+            type IntoIter = iter::Once<Self>;
+            fn into_iter(self) -> Self::IntoIter {
+                iter::once(self)
+            }
+        }
+
+        /// Scale by a factor, checking that the product is in bounds.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Mul<$inner> for $clamped<LOWER, UPPER> {
+            type Output = Result<Self, ArithmeticError<$inner>>;
+            fn mul(self, rhs: $inner) -> Self::Output {
+                match self.0.checked_mul(rhs) {
+                    Some(product) => Self::try_from(product).map_err(ArithmeticError::OutOfBounds),
+                    None => Err(ArithmeticError::Overflow { given: self.0 }),
+                }
+            }
+        }
+
+        /// The static bounds of the type, `LOWER..UPPER`.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::RangeBounds<$inner> for $clamped<LOWER, UPPER> {
+            fn start_bound(&self) -> ops::Bound<&$inner> {
+                ops::Bound::Included(&LOWER)
+            }
+            fn end_bound(&self) -> ops::Bound<&$inner> {
+                ops::Bound::Excluded(&UPPER)
+            }
+        }
+
+        /// Zero, like the inner integer.
+        ///
+        /// Fails to compile if zero is not in the range.
+        impl<const LOWER: $inner, const UPPER: $inner> Default for $clamped<LOWER, UPPER> {
+            fn default() -> Self {
+                const { assert!(Self::contains(0), "zero is not in the range") };
+                Self(0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<const LOWER: $inner, const UPPER: $inner> serde::Serialize for $clamped<LOWER, UPPER> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, const LOWER: $inner, const UPPER: $inner> serde::Deserialize<'de> for $clamped<LOWER, UPPER> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let inner = LenientVisitor::<$inner>::deserialize(deserializer)?;
+                Self::try_from(inner).map_err(serde::de::Error::custom)
+            }
+        }
+
+        /// The remainder is the inner integer, and is not re-validated against `LOWER..UPPER`.
+        ///
+        /// There is no `checked_rem` returning `Self`; use [`TryFrom`] on the remainder to re-validate.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Rem<$inner> for $clamped<LOWER, UPPER> {
+            type Output = $inner;
+            /// # Panics
+            /// As for the inner integer, if `rhs` is zero, or for `MIN % -1`.
+            fn rem(self, rhs: $inner) -> $inner {
+                self.0 % rhs
+            }
+        }
+
+        /// The remainder is smaller in magnitude than `rhs`, so is representable as the inner integer,
+        /// but is not necessarily in `LOWER..UPPER`.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Rem for $clamped<LOWER, UPPER> {
+            type Output = $inner;
+            /// # Panics
+            /// As for the inner integer, if `rhs` is zero, or for `MIN % -1`.
+            fn rem(self, rhs: Self) -> $inner {
+                self.0 % rhs.0
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> ops::AddAssign<$inner> for $clamped<LOWER, UPPER> {
+            /// # Panics
+            /// In debug mode if the result is out of bounds.
+            /// In release mode, the value is left unchanged instead.
+            fn add_assign(&mut self, rhs: $inner) {
+                let sum = self.0.checked_add(rhs).and_then(|it| Self::try_from(it).ok());
+                debug_assert!(sum.is_some(), "{:?} + {:?} is out of bounds", self, rhs);
+                if let Some(sum) = sum {
+                    *self = sum
+                }
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> AsRef<$inner> for $clamped<LOWER, UPPER> {
+            fn as_ref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> SaturatingFrom<$inner> for $clamped<LOWER, UPPER> {
+            fn saturating_from(inner: $inner) -> Self {
+                Self::saturating_from(inner)
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> sealed::Step for $clamped<LOWER, UPPER> {
+            fn succ(self) -> Self {
+                Self(self.0 + 1)
+            }
+            fn pred(self) -> Self {
+                Self(self.0 - 1)
+            }
+            fn distance(self, to: Self) -> Option<usize> {
+                usize::try_from(to.0.wrapping_sub(self.0) as $unsigned).ok()
+            }
+        }
+
+        /// A single leading `+` is accepted, as for the inner integer.
+        impl<const LOWER: $inner, const UPPER: $inner> str::FromStr for $clamped<LOWER, UPPER> {
+            type Err = ParseClampedError<<Self as TryFrom<$inner>>::Error>;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let inner = s.parse::<$inner>().map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> TryFrom<$inner> for $clamped<LOWER, UPPER> {
+            type Error = OutOfBounds<$inner>;
+            /// # Panics
+            /// In debug mode if `!(LOWER < UPPER)`
             fn try_from(inner: $inner) -> Result<Self, Self::Error> {
-                if inner > UPPER {
-                    Err(OutOfBoundsTo {
+                debug_assert!(LOWER < UPPER);
+                if inner < LOWER || inner >= UPPER {
+                    Err(OutOfBounds {
+                        lower: LOWER,
                         upper: UPPER,
                         given: inner,
                     })
@@ -263,13 +1170,22 @@ macro_rules! clamped {
             }
         }
 
-        impl<const UPPER: $inner> From<$clamped_to_inclusive<UPPER>> for $inner {
-            fn from(clamped: $clamped_to_inclusive<UPPER>) -> $inner {
+        impl<const LOWER: $inner, const UPPER: $inner> From<$clamped<LOWER, UPPER>> for $inner {
+            fn from(clamped: $clamped<LOWER, UPPER>) -> $inner {
                 clamped.0
             }
         }
 
-        impl<const UPPER: $inner> fmt::Debug for $clamped_to_inclusive<UPPER> {
+        #[cfg(feature = "precise-debug")]
+        impl<const LOWER: $inner, const UPPER: $inner> fmt::Debug for $clamped<LOWER, UPPER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let name = format!("{}<{}, {}>", stringify!($clamped), LOWER, UPPER);
+                f.debug_tuple(&name).field(&self.0).finish()
+            }
+        }
+
+        #[cfg(not(feature = "precise-debug"))]
+        impl<const LOWER: $inner, const UPPER: $inner> fmt::Debug for $clamped<LOWER, UPPER> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 // #[derive(Debug)] doesn't preserve the const type parameters
                 // Use type_name instead, and strip the module path
@@ -281,153 +1197,5975 @@ macro_rules! clamped {
             }
         }
 
-        impl<const UPPER: $inner> PartialEq<$inner> for $clamped_to_inclusive<UPPER> {
+        impl<const LOWER: $inner, const UPPER: $inner> PartialEq<$inner>
+            for $clamped<LOWER, UPPER>
+        {
             fn eq(&self, other: &$inner) -> bool {
                 self.0 == *other
             }
         }
-    };
-}
 
-clamped!(
-    u8,
-    ClampedU8,
-    ClampedU8From,
-    ClampedU8Inclusive,
-    ClampedU8To,
-    ClampedU8ToInclusive,
-);
-clamped!(
-    u16,
-    ClampedU16,
-    ClampedU16From,
-    ClampedU16Inclusive,
-    ClampedU16To,
-    ClampedU16ToInclusive,
-);
-clamped!(
-    u32,
-    ClampedU32,
-    ClampedU32From,
-    ClampedU32Inclusive,
-    ClampedU32To,
-    ClampedU32ToInclusive,
-);
-clamped!(
-    u64,
-    ClampedU64,
-    ClampedU64From,
-    ClampedU64Inclusive,
-    ClampedU64To,
-    ClampedU64ToInclusive,
-);
-clamped!(
-    u128,
-    ClampedU128,
+        impl<const LOWER: $inner, const UPPER: $inner> PartialEq<$clamped<LOWER, UPPER>> for $inner {
+            fn eq(&self, other: &$clamped<LOWER, UPPER>) -> bool {
+                *self == other.0
+            }
+        }
+
+        /// An integer only bounded inclusively below `LOWER..`.
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $clamped_from<const LOWER: $inner>($inner);
+
+        impl<const LOWER: $inner> $clamped_from<LOWER> {
+            /// The inner integer.
+            pub const fn get(self) -> $inner {
+                self.0
+            }
+
+            /// The inner integer, identical to [`Self::get`].
+            pub const fn as_inner_copy(self) -> $inner {
+                self.0
+            }
+
+            /// Construct, performing the same validation as [`TryFrom`].
+            pub fn new(inner: $inner) -> Result<Self, <Self as TryFrom<$inner>>::Error> {
+                Self::try_from(inner)
+            }
+
+            /// Construct, naming the field in the error on failure.
+            pub fn try_new_named(
+                inner: $inner,
+                field: &'static str,
+            ) -> Result<Self, NamedOutOfBounds<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(inner).map_err(|source| NamedOutOfBounds { field, source })
+            }
+
+            /// [`PartialEq::eq`], usable in `const` contexts.
+            pub const fn const_eq(self, other: Self) -> bool {
+                self.0 == other.0
+            }
+
+            /// [`Ord::cmp`], usable in `const` contexts.
+            pub const fn const_cmp(self, other: Self) -> cmp::Ordering {
+                if self.0 < other.0 {
+                    cmp::Ordering::Less
+                } else if self.0 > other.0 {
+                    cmp::Ordering::Greater
+                } else {
+                    cmp::Ordering::Equal
+                }
+            }
+
+            /// Construct, pinning values below `LOWER` up to `LOWER`.
+            ///
+            /// Usable in `const` contexts, to build tables of valid values from raw constants.
+            pub const fn clamp_const(inner: $inner) -> Self {
+                if inner < LOWER {
+                    Self(LOWER)
+                } else {
+                    Self(inner)
+                }
+            }
+
+            /// Construct, pinning values outside the range into it, as [`Self::clamp_const`].
+            pub const fn saturating_from(inner: $inner) -> Self {
+                Self::clamp_const(inner)
+            }
+
+            /// Step one closer to `target` (pinned into the range), for shrinking test cases.
+            ///
+            /// Returns [`None`] if already at `target`.
+            pub fn shrink_toward(self, target: $inner) -> Option<Self> {
+                let target = Self::saturating_from(target).0;
+                // `target` is in the range, so every value between it and `self` is too
+                match self.0.cmp(&target) {
+                    cmp::Ordering::Greater => Some(Self(self.0 - 1)),
+                    cmp::Ordering::Less => Some(Self(self.0 + 1)),
+                    cmp::Ordering::Equal => None,
+                }
+            }
+
+            /// Collect `values` into a sorted [`Vec`].
+            pub fn sorted(values: impl IntoIterator<Item = Self>) -> Vec<Self> {
+                let mut values = values.into_iter().collect::<Vec<_>>();
+                values.sort_unstable();
+                values
+            }
+
+            /// Decode from the little-endian bytes at the front of `bytes`.
+            pub fn try_from_le_slice(
+                bytes: &[u8],
+            ) -> Result<Self, DecodeError<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(<$inner>::from_le_bytes(Self::front(bytes)?))
+                    .map_err(DecodeError::OutOfBounds)
+            }
+
+            /// Decode from the big-endian bytes at the front of `bytes`.
+            pub fn try_from_be_slice(
+                bytes: &[u8],
+            ) -> Result<Self, DecodeError<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(<$inner>::from_be_bytes(Self::front(bytes)?))
+                    .map_err(DecodeError::OutOfBounds)
+            }
+
+            fn front(
+                bytes: &[u8],
+            ) -> Result<[u8; mem::size_of::<$inner>()], DecodeError<<Self as TryFrom<$inner>>::Error>>
+            {
+                match bytes.first_chunk() {
+                    Some(front) => Ok(*front),
+                    None => Err(DecodeError::InsufficientBytes {
+                        needed: mem::size_of::<$inner>(),
+                        given: bytes.len(),
+                    }),
+                }
+            }
+
+            /// The bitwise complement, returning [`None`] if it is out of bounds.
+            pub fn checked_not(self) -> Option<Self> {
+                Self::try_from(!self.0).ok()
+            }
+
+            /// Convert a slice of exactly `N` elements, validating each one.
+            pub fn try_from_slice_array<const N: usize>(
+                inners: &[$inner],
+            ) -> Result<[Self; N], ArrayConvertError<<Self as TryFrom<$inner>>::Error>> {
+                let array = <[$inner; N]>::try_from(inners).map_err(|_| ArrayConvertError::Length {
+                    expected: N,
+                    given: inners.len(),
+                })?;
+                for (index, inner) in array.into_iter().enumerate() {
+                    Self::try_from(inner)
+                        .map_err(|source| ArrayConvertError::OutOfBounds { index, source })?;
+                }
+                Ok(array.map(Self))
+            }
+
+            /// Every value in the range, in ascending order.
+            pub fn iter() -> Iter<Self> {
+                Iter {
+                    remaining: Some((Self(LOWER), Self(<$inner>::MAX))),
+                }
+            }
+
+            /// The smallest of `values`, or [`None`] if there are none.
+            pub fn min_of(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+                values.into_iter().min()
+            }
+
+            /// The largest of `values`, or [`None`] if there are none.
+            pub fn max_of(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+                values.into_iter().max()
+            }
+
+            /// Whether `inner` is in the range.
+            pub const fn contains(inner: $inner) -> bool {
+                LOWER <= inner
+            }
+
+            /// Every value from `start` onwards, in ascending order.
+            pub fn iter_from(start: Self) -> Iter<Self> {
+                Iter {
+                    remaining: Self::iter().remaining.map(|(_, back)| (start, back)),
+                }
+            }
+
+            /// Every value before `end`, in ascending order.
+            pub fn iter_to(end: Self) -> Iter<Self> {
+                Iter {
+                    remaining: Self::iter().remaining.and_then(|(front, _)| match front == end {
+                        true => None,
+                        false => Some((front, Self(end.0 - 1))),
+                    }),
+                }
+            }
+
+            /// Convert every value, or report every value that is out of bounds.
+            pub fn validate_all(
+                inners: &[$inner],
+            ) -> Result<Vec<Self>, Vec<(usize, <Self as TryFrom<$inner>>::Error)>> {
+                let mut oks = Vec::with_capacity(inners.len());
+                let mut errs = Vec::new();
+                for (index, inner) in inners.iter().enumerate() {
+                    match Self::try_from(*inner) {
+                        Ok(ok) => oks.push(ok),
+                        Err(err) => errs.push((index, err)),
+                    }
+                }
+                match errs.is_empty() {
+                    true => Ok(oks),
+                    false => Err(errs),
+                }
+            }
+
+            /// Round to the nearest integer, pinning the result into the range.
+            ///
+            /// NaN is mapped to the lowest value in the range.
+            pub fn from_f64_saturating(value: f64) -> Self {
+                match value.is_nan() {
+                    true => Self::saturating_from(<$inner>::MIN),
+                    // `as` saturates at the limits of the inner type
+                    false => Self::saturating_from(value.round() as $inner),
+                }
+            }
+
+            /// Pin into `min..=max` as well as the range, mirroring [`Ord::clamp`].
+            ///
+            /// # Panics
+            /// In debug mode if `min > max`, or if no value in the range is in `min..=max`.
+            pub fn clamp_to(self, min: $inner, max: $inner) -> Self {
+                debug_assert!(min <= max);
+                let lowest = Self::clamp_const(min).0;
+                debug_assert!(
+                    min <= lowest && lowest <= max,
+                    "no value in range is in {min:?}..={max:?}"
+                );
+                Self::clamp_const(self.0.max(min).min(max))
+            }
+
+            /// Re-validate against a new bound.
+            pub fn checked_rebound<const L2: $inner>(
+                self,
+            ) -> Result<$clamped_from<L2>, OutOfBoundsFrom<$inner>> {
+                $clamped_from::try_from(self.0)
+            }
+
+            /// Whether every value in this range is also in `L2..`,
+            /// so that [`Self::checked_rebound`] always succeeds.
+            pub const fn is_subrange_of<const L2: $inner>() -> bool {
+                L2 <= LOWER
+            }
+
+            /// The inner integer, for use with e.g. [`slice::sort_by_key`].
+            pub const fn sort_key(self) -> $inner {
+                self.0
+            }
+
+            /// Accumulate base-10 `digits`, each in `0..=9`, most significant first.
+            ///
+            /// Errors are as for [`FromStr`](str::FromStr), e.g. for empty input, or digits out of range.
+            pub fn from_digits(
+                digits: impl IntoIterator<Item = u8>,
+            ) -> Result<Self, ParseClampedError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = parse_digits::<$inner>(digits).map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+
+            /// Every value in ascending order, repeated endlessly.
+            ///
+            /// Yields nothing if the range is empty.
+            pub fn cycle() -> impl Iterator<Item = Self> {
+                Self::iter().cycle()
+            }
+
+            /// Tighten to the half-open range `LOWER..U`.
+            pub fn with_upper<const U: $inner>(
+                self,
+            ) -> Result<$clamped<LOWER, U>, OutOfBounds<$inner>> {
+                $clamped::try_from(self.0)
+            }
+
+            /// Round to the nearest integer, pinning the result into the range.
+            ///
+            /// NaN is mapped to the lowest value in the range.
+            pub fn from_f32_saturating(value: f32) -> Self {
+                match value.is_nan() {
+                    true => Self::saturating_from(<$inner>::MIN),
+                    // `as` saturates at the limits of the inner type
+                    false => Self::saturating_from(value.round() as $inner),
+                }
+            }
+
+            /// The bitwise OR, returning [`None`] if it is out of bounds.
+            pub fn checked_union(self, other: Self) -> Option<Self> {
+                Self::new(self.0 | other.0).ok()
+            }
+
+            /// Parse, detecting a `0x`, `0o` or `0b` prefix after any sign, and defaulting to decimal.
+            pub fn from_str_auto_radix(
+                src: &str,
+            ) -> Result<Self, ParseClampedError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = parse_auto_radix(src, <$inner>::from_str_radix)
+                    .map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+
+            /// Every entry of `map`, over the whole range.
+            pub fn range_query<V>(
+                map: &collections::BTreeMap<Self, V>,
+            ) -> collections::btree_map::Range<'_, Self, V> {
+                // every key is in the range, and this avoids constructing an inverted range if it is empty
+                map.range(..)
+            }
+
+            /// Render with `sep` between each group of three digits, e.g. `1,000,000`.
+            #[cfg(feature = "format")]
+            pub fn to_grouped_string(self, sep: char) -> String {
+                group_digits(&self.0.to_string(), sep)
+            }
+
+            /// The value halfway between this and `other`, rounding toward this, without overflow.
+            pub const fn midpoint_with(self, other: Self) -> Self {
+                // both values are in the range, so anything between them is too
+                if self.0 <= other.0 {
+                    let half = (other.0.wrapping_sub(self.0) as $unsigned / 2) as $inner;
+                    Self(self.0.wrapping_add(half))
+                } else {
+                    let half = (self.0.wrapping_sub(other.0) as $unsigned / 2) as $inner;
+                    Self(self.0.wrapping_sub(half))
+                }
+            }
+        }
+
+        /// As for the inner integer.
+        impl<const LOWER: $inner> fmt::Display for $clamped_from<LOWER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        /// As for [`FromStr`](str::FromStr).
+        impl<'a, const LOWER: $inner> TryFrom<&'a str> for $clamped_from<LOWER> {
+            type Error = ParseClampedError<<Self as TryFrom<$inner>>::Error>;
+            fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        /// Yields just this value.
+        ///
+        /// See [`Self::iter`] for every value in the range.
+        impl<const LOWER: $inner> IntoIterator for $clamped_from<LOWER> {
+            type Item = Self;
+            type IntoIter = iter::Once<Self>;
+            fn into_iter(self) -> Self::IntoIter {
+                iter::once(self)
+            }
+        }
+
+        /// The static bounds of the type, `LOWER..`.
+        impl<const LOWER: $inner> ops::RangeBounds<$inner> for $clamped_from<LOWER> {
+            fn start_bound(&self) -> ops::Bound<&$inner> {
+                ops::Bound::Included(&LOWER)
+            }
+            fn end_bound(&self) -> ops::Bound<&$inner> {
+                ops::Bound::Unbounded
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<const LOWER: $inner> serde::Serialize for $clamped_from<LOWER> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, const LOWER: $inner> serde::Deserialize<'de> for $clamped_from<LOWER> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let inner = LenientVisitor::<$inner>::deserialize(deserializer)?;
+                Self::try_from(inner).map_err(serde::de::Error::custom)
+            }
+        }
+
+        /// The remainder is the inner integer, and is not re-validated against `LOWER..`.
+        ///
+        /// There is no `checked_rem` returning `Self`; use [`TryFrom`] on the remainder to re-validate.
+        impl<const LOWER: $inner> ops::Rem<$inner> for $clamped_from<LOWER> {
+            type Output = $inner;
+            /// # Panics
+            /// As for the inner integer, if `rhs` is zero, or for `MIN % -1`.
+            fn rem(self, rhs: $inner) -> $inner {
+                self.0 % rhs
+            }
+        }
+
+        impl<const LOWER: $inner> AsRef<$inner> for $clamped_from<LOWER> {
+            fn as_ref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<const LOWER: $inner> SaturatingFrom<$inner> for $clamped_from<LOWER> {
+            fn saturating_from(inner: $inner) -> Self {
+                Self::saturating_from(inner)
+            }
+        }
+
+        impl<const LOWER: $inner> sealed::Step for $clamped_from<LOWER> {
+            fn succ(self) -> Self {
+                Self(self.0 + 1)
+            }
+            fn pred(self) -> Self {
+                Self(self.0 - 1)
+            }
+            fn distance(self, to: Self) -> Option<usize> {
+                usize::try_from(to.0.wrapping_sub(self.0) as $unsigned).ok()
+            }
+        }
+
+        /// A single leading `+` is accepted, as for the inner integer.
+        impl<const LOWER: $inner> str::FromStr for $clamped_from<LOWER> {
+            type Err = ParseClampedError<<Self as TryFrom<$inner>>::Error>;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let inner = s.parse::<$inner>().map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+        }
+
+        impl<const LOWER: $inner> TryFrom<$inner> for $clamped_from<LOWER> {
+            type Error = OutOfBoundsFrom<$inner>;
+            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
+                // the range always contains `MAX`, so cannot be empty
+                if inner < LOWER {
+                    Err(OutOfBoundsFrom {
+                        lower: LOWER,
+                        given: inner,
+                    })
+                } else {
+                    Ok(Self(inner))
+                }
+            }
+        }
+
+        impl<const LOWER: $inner> From<$clamped_from<LOWER>> for $inner {
+            fn from(clamped: $clamped_from<LOWER>) -> $inner {
+                clamped.0
+            }
+        }
+
+        #[cfg(feature = "precise-debug")]
+        impl<const LOWER: $inner> fmt::Debug for $clamped_from<LOWER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let name = format!("{}<{}>", stringify!($clamped_from), LOWER);
+                f.debug_tuple(&name).field(&self.0).finish()
+            }
+        }
+
+        #[cfg(not(feature = "precise-debug"))]
+        impl<const LOWER: $inner> fmt::Debug for $clamped_from<LOWER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // #[derive(Debug)] doesn't preserve the const type parameters
+                // Use type_name instead, and strip the module path
+                let name = match any::type_name::<Self>().rsplit_once("::") {
+                    Some((_pre, post)) => post,
+                    None => any::type_name::<Self>(),
+                };
+                f.debug_tuple(name).field(&self.0).finish()
+            }
+        }
+
+        impl<const LOWER: $inner> PartialEq<$inner> for $clamped_from<LOWER> {
+            fn eq(&self, other: &$inner) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<const LOWER: $inner> PartialEq<$clamped_from<LOWER>> for $inner {
+            fn eq(&self, other: &$clamped_from<LOWER>) -> bool {
+                *self == other.0
+            }
+        }
+
+        /// An integer bound in the inclusive range below and above `LOWER..=UPPER`.
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $clamped_inclusive<const LOWER: $inner, const UPPER: $inner>($inner);
+
+        impl<const LOWER: $inner, const UPPER: $inner> $clamped_inclusive<LOWER, UPPER> {
+            /// The inner integer.
+            pub const fn get(self) -> $inner {
+                self.0
+            }
+
+            /// The inner integer, identical to [`Self::get`].
+            pub const fn as_inner_copy(self) -> $inner {
+                self.0
+            }
+
+            /// Construct, performing the same validation as [`TryFrom`].
+            pub fn new(inner: $inner) -> Result<Self, <Self as TryFrom<$inner>>::Error> {
+                Self::try_from(inner)
+            }
+
+            /// Construct, naming the field in the error on failure.
+            pub fn try_new_named(
+                inner: $inner,
+                field: &'static str,
+            ) -> Result<Self, NamedOutOfBounds<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(inner).map_err(|source| NamedOutOfBounds { field, source })
+            }
+
+            /// [`PartialEq::eq`], usable in `const` contexts.
+            pub const fn const_eq(self, other: Self) -> bool {
+                self.0 == other.0
+            }
+
+            /// [`Ord::cmp`], usable in `const` contexts.
+            pub const fn const_cmp(self, other: Self) -> cmp::Ordering {
+                if self.0 < other.0 {
+                    cmp::Ordering::Less
+                } else if self.0 > other.0 {
+                    cmp::Ordering::Greater
+                } else {
+                    cmp::Ordering::Equal
+                }
+            }
+
+            /// Construct, pinning values outside the range to the nearest bound.
+            ///
+            /// Usable in `const` contexts, to build tables of valid values from raw constants.
+            ///
+            /// Fails to compile if the range is empty.
+            pub const fn clamp_const(inner: $inner) -> Self {
+                const { assert!(LOWER <= UPPER, "the range is empty") };
+                if inner < LOWER {
+                    Self(LOWER)
+                } else if inner > UPPER {
+                    Self(UPPER)
+                } else {
+                    Self(inner)
+                }
+            }
+
+            /// Construct, pinning values outside the range into it, as [`Self::clamp_const`].
+            ///
+            /// Fails to compile if the range is empty, since there is no value to pin to.
+            pub const fn saturating_from(inner: $inner) -> Self {
+                Self::clamp_const(inner)
+            }
+
+            /// The number of values in the range.
+            ///
+            /// Fails to compile if the range covers every value of the inner type.
+            pub const CARDINALITY: $unsigned = UPPER.wrapping_sub(LOWER) as $unsigned + 1;
+
+            /// The distance of this value from `LOWER`, in `0..CARDINALITY`.
+            pub const fn offset(self) -> $unsigned {
+                self.0.wrapping_sub(LOWER) as $unsigned
+            }
+
+            /// Count the occurrences of each value, indexed by [`Self::offset`].
+            ///
+            /// `N` must be [`Self::CARDINALITY`], or this will fail to compile.
+            pub fn histogram<const N: usize>(values: impl IntoIterator<Item = Self>) -> [usize; N] {
+                const { assert!(N as u128 == Self::CARDINALITY as u128) };
+                let mut counts = [0; N];
+                for value in values {
+                    counts[value.offset() as usize] += 1;
+                }
+                counts
+            }
+
+            /// Step one closer to `target` (pinned into the range), for shrinking test cases.
+            ///
+            /// Returns [`None`] if already at `target`.
+            pub fn shrink_toward(self, target: $inner) -> Option<Self> {
+                let target = Self::saturating_from(target).0;
+                // `target` is in the range, so every value between it and `self` is too
+                match self.0.cmp(&target) {
+                    cmp::Ordering::Greater => Some(Self(self.0 - 1)),
+                    cmp::Ordering::Less => Some(Self(self.0 + 1)),
+                    cmp::Ordering::Equal => None,
+                }
+            }
+
+            /// Collect `values` into a sorted [`Vec`].
+            pub fn sorted(values: impl IntoIterator<Item = Self>) -> Vec<Self> {
+                let mut values = values.into_iter().collect::<Vec<_>>();
+                values.sort_unstable();
+                values
+            }
+
+            /// The width of the inner integer, in bits.
+            pub const BITS: u32 = <$inner>::BITS;
+
+            /// The fewest bits that can represent every [`Self::offset`].
+            pub const NEEDED_BITS: u32 =
+                <$unsigned>::BITS - (UPPER.wrapping_sub(LOWER) as $unsigned).leading_zeros();
+
+            /// Decode from the little-endian bytes at the front of `bytes`.
+            pub fn try_from_le_slice(
+                bytes: &[u8],
+            ) -> Result<Self, DecodeError<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(<$inner>::from_le_bytes(Self::front(bytes)?))
+                    .map_err(DecodeError::OutOfBounds)
+            }
+
+            /// Decode from the big-endian bytes at the front of `bytes`.
+            pub fn try_from_be_slice(
+                bytes: &[u8],
+            ) -> Result<Self, DecodeError<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(<$inner>::from_be_bytes(Self::front(bytes)?))
+                    .map_err(DecodeError::OutOfBounds)
+            }
+
+            fn front(
+                bytes: &[u8],
+            ) -> Result<[u8; mem::size_of::<$inner>()], DecodeError<<Self as TryFrom<$inner>>::Error>>
+            {
+                match bytes.first_chunk() {
+                    Some(front) => Ok(*front),
+                    None => Err(DecodeError::InsufficientBytes {
+                        needed: mem::size_of::<$inner>(),
+                        given: bytes.len(),
+                    }),
+                }
+            }
+
+            /// The bitwise complement, returning [`None`] if it is out of bounds.
+            pub fn checked_not(self) -> Option<Self> {
+                Self::try_from(!self.0).ok()
+            }
+
+            /// Convert a slice of exactly `N` elements, validating each one.
+            pub fn try_from_slice_array<const N: usize>(
+                inners: &[$inner],
+            ) -> Result<[Self; N], ArrayConvertError<<Self as TryFrom<$inner>>::Error>> {
+                let array = <[$inner; N]>::try_from(inners).map_err(|_| ArrayConvertError::Length {
+                    expected: N,
+                    given: inners.len(),
+                })?;
+                for (index, inner) in array.into_iter().enumerate() {
+                    Self::try_from(inner)
+                        .map_err(|source| ArrayConvertError::OutOfBounds { index, source })?;
+                }
+                Ok(array.map(Self))
+            }
+
+            /// Every value in the range, in ascending order.
+            pub fn iter() -> Iter<Self> {
+                Iter {
+                    remaining: match LOWER <= UPPER {
+                        true => Some((Self(LOWER), Self(UPPER))),
+                        false => None,
+                    },
+                }
+            }
+
+            /// The smallest of `values`, or [`None`] if there are none.
+            pub fn min_of(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+                values.into_iter().min()
+            }
+
+            /// The largest of `values`, or [`None`] if there are none.
+            pub fn max_of(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+                values.into_iter().max()
+            }
+
+            /// Step up by one, returning [`None`] at the top of the range.
+            pub fn increment(self) -> Option<Self> {
+                Self::try_from(self.0.checked_add(1)?).ok()
+            }
+
+            /// Step down by one, returning [`None`] at the bottom of the range.
+            pub fn decrement(self) -> Option<Self> {
+                Self::try_from(self.0.checked_sub(1)?).ok()
+            }
+
+            /// Whether `inner` is in the range.
+            pub const fn contains(inner: $inner) -> bool {
+                LOWER <= inner && inner <= UPPER
+            }
+
+            /// Add `rhs`, wrapping around the range.
+            pub fn wrapping_add(self, rhs: $inner) -> Self {
+                let Some(cardinality) = (UPPER.wrapping_sub(LOWER) as $unsigned).checked_add(1)
+                else {
+                    // The range covers every value
+                    return Self(self.0.wrapping_add(rhs));
+                };
+                let (offset, rhs) = (self.offset(), Self::reduce_offset(rhs, cardinality));
+                let offset = match offset >= cardinality - rhs {
+                    true => offset - (cardinality - rhs),
+                    false => offset + rhs,
+                };
+                Self(LOWER.wrapping_add(offset as $inner))
+            }
+
+            /// Subtract `rhs`, wrapping around the range.
+            pub fn wrapping_sub(self, rhs: $inner) -> Self {
+                let Some(cardinality) = (UPPER.wrapping_sub(LOWER) as $unsigned).checked_add(1)
+                else {
+                    // The range covers every value
+                    return Self(self.0.wrapping_sub(rhs));
+                };
+                let (offset, rhs) = (self.offset(), Self::reduce_offset(rhs, cardinality));
+                let offset = match offset >= rhs {
+                    true => offset - rhs,
+                    false => offset + (cardinality - rhs),
+                };
+                Self(LOWER.wrapping_add(offset as $inner))
+            }
+
+            /// Step up by one, wrapping from the top of the range to `LOWER`.
+            pub fn wrapping_increment(self) -> Self {
+                self.wrapping_add(1)
+            }
+
+            /// Step down by one, wrapping from `LOWER` to the top of the range.
+            pub fn wrapping_decrement(self) -> Self {
+                self.wrapping_sub(1)
+            }
+
+            /// `rhs` modulo `cardinality`, as an offset.
+            fn reduce_offset(rhs: $inner, cardinality: $unsigned) -> $unsigned {
+                // rhs == (rhs - MIN) + MIN, where (rhs - MIN) is never negative
+                let shift = (<$inner>::MIN as $unsigned) % cardinality;
+                let rhs = (rhs.wrapping_sub(<$inner>::MIN) as $unsigned) % cardinality;
+                match rhs >= shift {
+                    true => rhs - shift,
+                    false => rhs + (cardinality - shift),
+                }
+            }
+
+            /// Every value from `start` onwards, in ascending order.
+            pub fn iter_from(start: Self) -> Iter<Self> {
+                Iter {
+                    remaining: Self::iter().remaining.map(|(_, back)| (start, back)),
+                }
+            }
+
+            /// Every value before `end`, in ascending order.
+            pub fn iter_to(end: Self) -> Iter<Self> {
+                Iter {
+                    remaining: Self::iter().remaining.and_then(|(front, _)| match front == end {
+                        true => None,
+                        false => Some((front, Self(end.0 - 1))),
+                    }),
+                }
+            }
+
+            /// Convert every value, or report every value that is out of bounds.
+            pub fn validate_all(
+                inners: &[$inner],
+            ) -> Result<Vec<Self>, Vec<(usize, <Self as TryFrom<$inner>>::Error)>> {
+                let mut oks = Vec::with_capacity(inners.len());
+                let mut errs = Vec::new();
+                for (index, inner) in inners.iter().enumerate() {
+                    match Self::try_from(*inner) {
+                        Ok(ok) => oks.push(ok),
+                        Err(err) => errs.push((index, err)),
+                    }
+                }
+                match errs.is_empty() {
+                    true => Ok(oks),
+                    false => Err(errs),
+                }
+            }
+
+            /// Shift left, returning [`None`] on overflow or if the result is out of bounds.
+            pub fn checked_shl(self, rhs: u32) -> Option<Self> {
+                Self::new(self.0.checked_shl(rhs)?).ok()
+            }
+
+            /// Shift right, returning [`None`] on overflow or if the result is out of bounds.
+            pub fn checked_shr(self, rhs: u32) -> Option<Self> {
+                Self::new(self.0.checked_shr(rhs)?).ok()
+            }
+
+            /// Round to the nearest integer, pinning the result into the range.
+            ///
+            /// NaN is mapped to the lowest value in the range.
+            pub fn from_f64_saturating(value: f64) -> Self {
+                match value.is_nan() {
+                    true => Self::saturating_from(<$inner>::MIN),
+                    // `as` saturates at the limits of the inner type
+                    false => Self::saturating_from(value.round() as $inner),
+                }
+            }
+
+            /// Pin into `min..=max` as well as the range, mirroring [`Ord::clamp`].
+            ///
+            /// # Panics
+            /// In debug mode if `min > max`, or if no value in the range is in `min..=max`.
+            pub fn clamp_to(self, min: $inner, max: $inner) -> Self {
+                debug_assert!(min <= max);
+                let lowest = Self::clamp_const(min).0;
+                debug_assert!(
+                    min <= lowest && lowest <= max,
+                    "no value in range is in {min:?}..={max:?}"
+                );
+                Self::clamp_const(self.0.max(min).min(max))
+            }
+
+            /// Re-validate against new bounds.
+            pub fn checked_rebound<const L2: $inner, const U2: $inner>(
+                self,
+            ) -> Result<$clamped_inclusive<L2, U2>, OutOfBoundsInclusive<$inner>> {
+                $clamped_inclusive::try_from(self.0)
+            }
+
+            /// Whether every value in this range is also in `L2..=U2`,
+            /// so that [`Self::checked_rebound`] always succeeds.
+            pub const fn is_subrange_of<const L2: $inner, const U2: $inner>() -> bool {
+                L2 <= LOWER && UPPER <= U2
+            }
+
+            /// The inner integer, for use with e.g. [`slice::sort_by_key`].
+            pub const fn sort_key(self) -> $inner {
+                self.0
+            }
+
+            /// Accumulate base-10 `digits`, each in `0..=9`, most significant first.
+            ///
+            /// Errors are as for [`FromStr`](str::FromStr), e.g. for empty input, or digits out of range.
+            pub fn from_digits(
+                digits: impl IntoIterator<Item = u8>,
+            ) -> Result<Self, ParseClampedError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = parse_digits::<$inner>(digits).map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+
+            /// Every value in ascending order, repeated endlessly.
+            ///
+            /// Yields nothing if the range is empty.
+            pub fn cycle() -> impl Iterator<Item = Self> {
+                Self::iter().cycle()
+            }
+
+            /// Whether this range shares any values with `L2..=U2`.
+            pub const fn overlaps_with<const L2: $inner, const U2: $inner>() -> bool {
+                LOWER <= U2 && L2 <= UPPER && LOWER <= UPPER && L2 <= U2
+            }
+
+            /// Round to the nearest integer, pinning the result into the range.
+            ///
+            /// NaN is mapped to the lowest value in the range.
+            pub fn from_f32_saturating(value: f32) -> Self {
+                match value.is_nan() {
+                    true => Self::saturating_from(<$inner>::MIN),
+                    // `as` saturates at the limits of the inner type
+                    false => Self::saturating_from(value.round() as $inner),
+                }
+            }
+
+            /// The bitwise OR, returning [`None`] if it is out of bounds.
+            pub fn checked_union(self, other: Self) -> Option<Self> {
+                Self::new(self.0 | other.0).ok()
+            }
+
+            /// Sample values clustered around `mode`, with a triangular distribution over the range.
+            ///
+            /// # Panics
+            /// If the range is empty.
+            #[cfg(feature = "rand")]
+            pub fn sample_triangular<R: rand::Rng + ?Sized>(rng: &mut R, mode: Self) -> Self {
+                assert!(LOWER <= UPPER, "the range is empty");
+                let sample = triangular(rng, LOWER as f64, UPPER as f64, mode.0 as f64);
+                Self::from_f64_saturating(sample)
+            }
+
+            /// Parse, detecting a `0x`, `0o` or `0b` prefix after any sign, and defaulting to decimal.
+            pub fn from_str_auto_radix(
+                src: &str,
+            ) -> Result<Self, ParseClampedError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = parse_auto_radix(src, <$inner>::from_str_radix)
+                    .map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+
+            /// Every entry of `map`, over the whole range.
+            pub fn range_query<V>(
+                map: &collections::BTreeMap<Self, V>,
+            ) -> collections::btree_map::Range<'_, Self, V> {
+                // every key is in the range, and this avoids constructing an inverted range if it is empty
+                map.range(..)
+            }
+
+            /// Render with `sep` between each group of three digits, e.g. `1,000,000`.
+            #[cfg(feature = "format")]
+            pub fn to_grouped_string(self, sep: char) -> String {
+                group_digits(&self.0.to_string(), sep)
+            }
+
+            /// Reflect across the middle of the range, so that the lowest value maps to the highest.
+            pub const fn mirror(self) -> Self {
+                // the true result is in the range, so wrapping can't lose anything
+                Self(LOWER.wrapping_add(UPPER).wrapping_sub(self.0))
+            }
+
+            /// The value halfway between this and `other`, rounding toward this, without overflow.
+            pub const fn midpoint_with(self, other: Self) -> Self {
+                // both values are in the range, so anything between them is too
+                if self.0 <= other.0 {
+                    let half = (other.0.wrapping_sub(self.0) as $unsigned / 2) as $inner;
+                    Self(self.0.wrapping_add(half))
+                } else {
+                    let half = (self.0.wrapping_sub(other.0) as $unsigned / 2) as $inner;
+                    Self(self.0.wrapping_sub(half))
+                }
+            }
+        }
+
+        /// As for the inner integer.
+        impl<const LOWER: $inner, const UPPER: $inner> fmt::Display for $clamped_inclusive<LOWER, UPPER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        /// As for [`FromStr`](str::FromStr).
+        impl<'a, const LOWER: $inner, const UPPER: $inner> TryFrom<&'a str> for $clamped_inclusive<LOWER, UPPER> {
+            type Error = ParseClampedError<<Self as TryFrom<$inner>>::Error>;
+            fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> From<Wrapping<$clamped_inclusive<LOWER, UPPER>>> for $clamped_inclusive<LOWER, UPPER> {
+            fn from(wrapping: Wrapping<$clamped_inclusive<LOWER, UPPER>>) -> Self {
+                wrapping.0
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Add<$inner> for Wrapping<$clamped_inclusive<LOWER, UPPER>> {
+            type Output = Self;
+            fn add(self, rhs: $inner) -> Self {
+                Wrapping(self.0.wrapping_add(rhs))
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Sub<$inner> for Wrapping<$clamped_inclusive<LOWER, UPPER>> {
+            type Output = Self;
+            fn sub(self, rhs: $inner) -> Self {
+                Wrapping(self.0.wrapping_sub(rhs))
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> ops::AddAssign<$inner> for Wrapping<$clamped_inclusive<LOWER, UPPER>> {
+            fn add_assign(&mut self, rhs: $inner) {
+                *self = *self + rhs
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> ops::SubAssign<$inner> for Wrapping<$clamped_inclusive<LOWER, UPPER>> {
+            fn sub_assign(&mut self, rhs: $inner) {
+                *self = *self - rhs
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> Bounded for $clamped_inclusive<LOWER, UPPER> {
+            const NEEDED_BITS: u32 = Self::NEEDED_BITS;
+            fn to_offset(self) -> u128 {
+                self.offset() as u128
+            }
+            fn from_offset(offset: u128) -> Option<Self> {
+                let offset = <$unsigned>::try_from(offset).ok()?;
+                // CARDINALITY wraps to zero for the full range
+                match offset <= UPPER.wrapping_sub(LOWER) as $unsigned {
+                    true => Some(Self(LOWER.wrapping_add(offset as $inner))),
+                    false => None,
+                }
+            }
+        }
+
+        /// Yields just this value.
+        ///
+        /// See [`Self::iter`] for every value in the range.
+        impl<const LOWER: $inner, const UPPER: $inner> IntoIterator for $clamped_inclusive<LOWER, UPPER> {
+            type Item = Self;
+            type IntoIter = iter::Once<Self>;
+            fn into_iter(self) -> Self::IntoIter {
+                iter::once(self)
+            }
+        }
+
+        /// The static bounds of the type, `LOWER..=UPPER`.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::RangeBounds<$inner> for $clamped_inclusive<LOWER, UPPER> {
+            fn start_bound(&self) -> ops::Bound<&$inner> {
+                ops::Bound::Included(&LOWER)
+            }
+            fn end_bound(&self) -> ops::Bound<&$inner> {
+                ops::Bound::Included(&UPPER)
+            }
+        }
+
+        /// Zero, like the inner integer.
+        ///
+        /// Fails to compile if zero is not in the range.
+        impl<const LOWER: $inner, const UPPER: $inner> Default for $clamped_inclusive<LOWER, UPPER> {
+            fn default() -> Self {
+                const { assert!(Self::contains(0), "zero is not in the range") };
+                Self(0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<const LOWER: $inner, const UPPER: $inner> serde::Serialize for $clamped_inclusive<LOWER, UPPER> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, const LOWER: $inner, const UPPER: $inner> serde::Deserialize<'de> for $clamped_inclusive<LOWER, UPPER> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let inner = LenientVisitor::<$inner>::deserialize(deserializer)?;
+                Self::try_from(inner).map_err(serde::de::Error::custom)
+            }
+        }
+
+        /// The remainder is the inner integer, and is not re-validated against `LOWER..=UPPER`.
+        ///
+        /// There is no `checked_rem` returning `Self`; use [`TryFrom`] on the remainder to re-validate.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Rem<$inner> for $clamped_inclusive<LOWER, UPPER> {
+            type Output = $inner;
+            /// # Panics
+            /// As for the inner integer, if `rhs` is zero, or for `MIN % -1`.
+            fn rem(self, rhs: $inner) -> $inner {
+                self.0 % rhs
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> ops::AddAssign<$inner> for $clamped_inclusive<LOWER, UPPER> {
+            /// # Panics
+            /// In debug mode if the result is out of bounds.
+            /// In release mode, the value is left unchanged instead.
+            fn add_assign(&mut self, rhs: $inner) {
+                let sum = self.0.checked_add(rhs).and_then(|it| Self::try_from(it).ok());
+                debug_assert!(sum.is_some(), "{:?} + {:?} is out of bounds", self, rhs);
+                if let Some(sum) = sum {
+                    *self = sum
+                }
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> AsRef<$inner> for $clamped_inclusive<LOWER, UPPER> {
+            fn as_ref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> SaturatingFrom<$inner> for $clamped_inclusive<LOWER, UPPER> {
+            fn saturating_from(inner: $inner) -> Self {
+                Self::saturating_from(inner)
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> sealed::Step for $clamped_inclusive<LOWER, UPPER> {
+            fn succ(self) -> Self {
+                Self(self.0 + 1)
+            }
+            fn pred(self) -> Self {
+                Self(self.0 - 1)
+            }
+            fn distance(self, to: Self) -> Option<usize> {
+                usize::try_from(to.0.wrapping_sub(self.0) as $unsigned).ok()
+            }
+        }
+
+        /// A single leading `+` is accepted, as for the inner integer.
+        impl<const LOWER: $inner, const UPPER: $inner> str::FromStr
+            for $clamped_inclusive<LOWER, UPPER>
+        {
+            type Err = ParseClampedError<<Self as TryFrom<$inner>>::Error>;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let inner = s.parse::<$inner>().map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> TryFrom<$inner>
+            for $clamped_inclusive<LOWER, UPPER>
+        {
+            type Error = OutOfBoundsInclusive<$inner>;
+            /// # Panics
+            /// In debug mode if `!(LOWER <= UPPER)`
+            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
+                debug_assert!(LOWER <= UPPER);
+                if inner < LOWER || inner > UPPER {
+                    Err(OutOfBoundsInclusive {
+                        lower: LOWER,
+                        upper: UPPER,
+                        given: inner,
+                    })
+                } else {
+                    Ok(Self(inner))
+                }
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> From<$clamped_inclusive<LOWER, UPPER>>
+            for $inner
+        {
+            fn from(clamped: $clamped_inclusive<LOWER, UPPER>) -> $inner {
+                clamped.0
+            }
+        }
+
+        #[cfg(feature = "precise-debug")]
+        impl<const LOWER: $inner, const UPPER: $inner> fmt::Debug
+            for $clamped_inclusive<LOWER, UPPER>
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let name = format!("{}<{}, {}>", stringify!($clamped_inclusive), LOWER, UPPER);
+                f.debug_tuple(&name).field(&self.0).finish()
+            }
+        }
+
+        #[cfg(not(feature = "precise-debug"))]
+        impl<const LOWER: $inner, const UPPER: $inner> fmt::Debug
+            for $clamped_inclusive<LOWER, UPPER>
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // #[derive(Debug)] doesn't preserve the const type parameters
+                // Use type_name instead, and strip the module path
+                let name = match any::type_name::<Self>().rsplit_once("::") {
+                    Some((_pre, post)) => post,
+                    None => any::type_name::<Self>(),
+                };
+                f.debug_tuple(name).field(&self.0).finish()
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> PartialEq<$inner>
+            for $clamped_inclusive<LOWER, UPPER>
+        {
+            fn eq(&self, other: &$inner) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> PartialEq<$clamped_inclusive<LOWER, UPPER>> for $inner {
+            fn eq(&self, other: &$clamped_inclusive<LOWER, UPPER>) -> bool {
+                *self == other.0
+            }
+        }
+
+        /// An integer bound in the exclusive range above `..UPPER`.
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $clamped_to<const UPPER: $inner>($inner);
+
+        impl<const UPPER: $inner> $clamped_to<UPPER> {
+            /// The inner integer.
+            pub const fn get(self) -> $inner {
+                self.0
+            }
+
+            /// The inner integer, identical to [`Self::get`].
+            pub const fn as_inner_copy(self) -> $inner {
+                self.0
+            }
+
+            /// Construct, performing the same validation as [`TryFrom`].
+            pub fn new(inner: $inner) -> Result<Self, <Self as TryFrom<$inner>>::Error> {
+                Self::try_from(inner)
+            }
+
+            /// Construct, naming the field in the error on failure.
+            pub fn try_new_named(
+                inner: $inner,
+                field: &'static str,
+            ) -> Result<Self, NamedOutOfBounds<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(inner).map_err(|source| NamedOutOfBounds { field, source })
+            }
+
+            /// [`PartialEq::eq`], usable in `const` contexts.
+            pub const fn const_eq(self, other: Self) -> bool {
+                self.0 == other.0
+            }
+
+            /// [`Ord::cmp`], usable in `const` contexts.
+            pub const fn const_cmp(self, other: Self) -> cmp::Ordering {
+                if self.0 < other.0 {
+                    cmp::Ordering::Less
+                } else if self.0 > other.0 {
+                    cmp::Ordering::Greater
+                } else {
+                    cmp::Ordering::Equal
+                }
+            }
+
+            /// Construct by wrapping `value` around the range, e.g. to hash into `UPPER` buckets.
+            ///
+            /// # Panics
+            /// If the range is empty.
+            pub fn from_wrapping(value: $inner) -> Self {
+                debug_assert!(UPPER > <$inner>::MIN);
+                let offset = value.wrapping_sub(<$inner>::MIN) as $unsigned;
+                let cardinality = UPPER.wrapping_sub(<$inner>::MIN) as $unsigned;
+                Self(<$inner>::MIN.wrapping_add((offset % cardinality) as $inner))
+            }
+
+            /// Construct, pinning values `>= UPPER` down to `UPPER - 1`.
+            ///
+            /// Usable in `const` contexts, to build tables of valid values from raw constants.
+            ///
+            /// Fails to compile if the range is empty.
+            pub const fn clamp_const(inner: $inner) -> Self {
+                const { assert!(UPPER > <$inner>::MIN, "the range is empty") };
+                if inner >= UPPER {
+                    Self(UPPER - 1)
+                } else {
+                    Self(inner)
+                }
+            }
+
+            /// Construct, pinning values outside the range into it, as [`Self::clamp_const`].
+            ///
+            /// Fails to compile if the range is empty, since there is no value to pin to.
+            pub const fn saturating_from(inner: $inner) -> Self {
+                Self::clamp_const(inner)
+            }
+
+            /// Step one closer to `target` (pinned into the range), for shrinking test cases.
+            ///
+            /// Returns [`None`] if already at `target`.
+            pub fn shrink_toward(self, target: $inner) -> Option<Self> {
+                let target = Self::saturating_from(target).0;
+                // `target` is in the range, so every value between it and `self` is too
+                match self.0.cmp(&target) {
+                    cmp::Ordering::Greater => Some(Self(self.0 - 1)),
+                    cmp::Ordering::Less => Some(Self(self.0 + 1)),
+                    cmp::Ordering::Equal => None,
+                }
+            }
+
+            /// Collect `values` into a sorted [`Vec`].
+            pub fn sorted(values: impl IntoIterator<Item = Self>) -> Vec<Self> {
+                let mut values = values.into_iter().collect::<Vec<_>>();
+                values.sort_unstable();
+                values
+            }
+
+            /// Decode from the little-endian bytes at the front of `bytes`.
+            pub fn try_from_le_slice(
+                bytes: &[u8],
+            ) -> Result<Self, DecodeError<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(<$inner>::from_le_bytes(Self::front(bytes)?))
+                    .map_err(DecodeError::OutOfBounds)
+            }
+
+            /// Decode from the big-endian bytes at the front of `bytes`.
+            pub fn try_from_be_slice(
+                bytes: &[u8],
+            ) -> Result<Self, DecodeError<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(<$inner>::from_be_bytes(Self::front(bytes)?))
+                    .map_err(DecodeError::OutOfBounds)
+            }
+
+            fn front(
+                bytes: &[u8],
+            ) -> Result<[u8; mem::size_of::<$inner>()], DecodeError<<Self as TryFrom<$inner>>::Error>>
+            {
+                match bytes.first_chunk() {
+                    Some(front) => Ok(*front),
+                    None => Err(DecodeError::InsufficientBytes {
+                        needed: mem::size_of::<$inner>(),
+                        given: bytes.len(),
+                    }),
+                }
+            }
+
+            /// The bitwise complement, returning [`None`] if it is out of bounds.
+            pub fn checked_not(self) -> Option<Self> {
+                Self::try_from(!self.0).ok()
+            }
+
+            /// Convert a slice of exactly `N` elements, validating each one.
+            pub fn try_from_slice_array<const N: usize>(
+                inners: &[$inner],
+            ) -> Result<[Self; N], ArrayConvertError<<Self as TryFrom<$inner>>::Error>> {
+                let array = <[$inner; N]>::try_from(inners).map_err(|_| ArrayConvertError::Length {
+                    expected: N,
+                    given: inners.len(),
+                })?;
+                for (index, inner) in array.into_iter().enumerate() {
+                    Self::try_from(inner)
+                        .map_err(|source| ArrayConvertError::OutOfBounds { index, source })?;
+                }
+                Ok(array.map(Self))
+            }
+
+            /// Every value in the range, in ascending order.
+            pub fn iter() -> Iter<Self> {
+                Iter {
+                    remaining: match UPPER > <$inner>::MIN {
+                        true => Some((Self(<$inner>::MIN), Self(UPPER - 1))),
+                        false => None,
+                    },
+                }
+            }
+
+            /// The smallest of `values`, or [`None`] if there are none.
+            pub fn min_of(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+                values.into_iter().min()
+            }
+
+            /// The largest of `values`, or [`None`] if there are none.
+            pub fn max_of(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+                values.into_iter().max()
+            }
+
+            /// Whether `inner` is in the range.
+            pub const fn contains(inner: $inner) -> bool {
+                inner < UPPER
+            }
+
+            /// Every value from `start` onwards, in ascending order.
+            pub fn iter_from(start: Self) -> Iter<Self> {
+                Iter {
+                    remaining: Self::iter().remaining.map(|(_, back)| (start, back)),
+                }
+            }
+
+            /// Every value before `end`, in ascending order.
+            pub fn iter_to(end: Self) -> Iter<Self> {
+                Iter {
+                    remaining: Self::iter().remaining.and_then(|(front, _)| match front == end {
+                        true => None,
+                        false => Some((front, Self(end.0 - 1))),
+                    }),
+                }
+            }
+
+            /// Convert every value, or report every value that is out of bounds.
+            pub fn validate_all(
+                inners: &[$inner],
+            ) -> Result<Vec<Self>, Vec<(usize, <Self as TryFrom<$inner>>::Error)>> {
+                let mut oks = Vec::with_capacity(inners.len());
+                let mut errs = Vec::new();
+                for (index, inner) in inners.iter().enumerate() {
+                    match Self::try_from(*inner) {
+                        Ok(ok) => oks.push(ok),
+                        Err(err) => errs.push((index, err)),
+                    }
+                }
+                match errs.is_empty() {
+                    true => Ok(oks),
+                    false => Err(errs),
+                }
+            }
+
+            /// Round to the nearest integer, pinning the result into the range.
+            ///
+            /// NaN is mapped to the lowest value in the range.
+            pub fn from_f64_saturating(value: f64) -> Self {
+                match value.is_nan() {
+                    true => Self::saturating_from(<$inner>::MIN),
+                    // `as` saturates at the limits of the inner type
+                    false => Self::saturating_from(value.round() as $inner),
+                }
+            }
+
+            /// Pin into `min..=max` as well as the range, mirroring [`Ord::clamp`].
+            ///
+            /// # Panics
+            /// In debug mode if `min > max`, or if no value in the range is in `min..=max`.
+            pub fn clamp_to(self, min: $inner, max: $inner) -> Self {
+                debug_assert!(min <= max);
+                let lowest = Self::clamp_const(min).0;
+                debug_assert!(
+                    min <= lowest && lowest <= max,
+                    "no value in range is in {min:?}..={max:?}"
+                );
+                Self::clamp_const(self.0.max(min).min(max))
+            }
+
+            /// Re-validate against a new bound.
+            pub fn checked_rebound<const U2: $inner>(
+                self,
+            ) -> Result<$clamped_to<U2>, OutOfBoundsTo<$inner>> {
+                $clamped_to::try_from(self.0)
+            }
+
+            /// Whether every value in this range is also in the range bounded by `U2`,
+            /// so that [`Self::checked_rebound`] always succeeds.
+            pub const fn is_subrange_of<const U2: $inner>() -> bool {
+                UPPER <= U2
+            }
+
+            /// The inner integer, for use with e.g. [`slice::sort_by_key`].
+            pub const fn sort_key(self) -> $inner {
+                self.0
+            }
+
+            /// Accumulate base-10 `digits`, each in `0..=9`, most significant first.
+            ///
+            /// Errors are as for [`FromStr`](str::FromStr), e.g. for empty input, or digits out of range.
+            pub fn from_digits(
+                digits: impl IntoIterator<Item = u8>,
+            ) -> Result<Self, ParseClampedError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = parse_digits::<$inner>(digits).map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+
+            /// Every value in ascending order, repeated endlessly.
+            ///
+            /// Yields nothing if the range is empty.
+            pub fn cycle() -> impl Iterator<Item = Self> {
+                Self::iter().cycle()
+            }
+
+            /// Tighten to the half-open range `L..UPPER`.
+            pub fn with_lower<const L: $inner>(
+                self,
+            ) -> Result<$clamped<L, UPPER>, OutOfBounds<$inner>> {
+                $clamped::try_from(self.0)
+            }
+
+            /// Round to the nearest integer, pinning the result into the range.
+            ///
+            /// NaN is mapped to the lowest value in the range.
+            pub fn from_f32_saturating(value: f32) -> Self {
+                match value.is_nan() {
+                    true => Self::saturating_from(<$inner>::MIN),
+                    // `as` saturates at the limits of the inner type
+                    false => Self::saturating_from(value.round() as $inner),
+                }
+            }
+
+            /// The bitwise OR, returning [`None`] if it is out of bounds.
+            pub fn checked_union(self, other: Self) -> Option<Self> {
+                Self::new(self.0 | other.0).ok()
+            }
+
+            /// Parse, detecting a `0x`, `0o` or `0b` prefix after any sign, and defaulting to decimal.
+            pub fn from_str_auto_radix(
+                src: &str,
+            ) -> Result<Self, ParseClampedError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = parse_auto_radix(src, <$inner>::from_str_radix)
+                    .map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+
+            /// Every entry of `map`, over the whole range.
+            pub fn range_query<V>(
+                map: &collections::BTreeMap<Self, V>,
+            ) -> collections::btree_map::Range<'_, Self, V> {
+                // every key is in the range, and this avoids constructing an inverted range if it is empty
+                map.range(..)
+            }
+
+            /// Render with `sep` between each group of three digits, e.g. `1,000,000`.
+            #[cfg(feature = "format")]
+            pub fn to_grouped_string(self, sep: char) -> String {
+                group_digits(&self.0.to_string(), sep)
+            }
+
+            /// The value halfway between this and `other`, rounding toward this, without overflow.
+            pub const fn midpoint_with(self, other: Self) -> Self {
+                // both values are in the range, so anything between them is too
+                if self.0 <= other.0 {
+                    let half = (other.0.wrapping_sub(self.0) as $unsigned / 2) as $inner;
+                    Self(self.0.wrapping_add(half))
+                } else {
+                    let half = (self.0.wrapping_sub(other.0) as $unsigned / 2) as $inner;
+                    Self(self.0.wrapping_sub(half))
+                }
+            }
+        }
+
+        /// As for the inner integer.
+        impl<const UPPER: $inner> fmt::Display for $clamped_to<UPPER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        /// As for [`FromStr`](str::FromStr).
+        impl<'a, const UPPER: $inner> TryFrom<&'a str> for $clamped_to<UPPER> {
+            type Error = ParseClampedError<<Self as TryFrom<$inner>>::Error>;
+            fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        /// The lowest value, `MIN`, which is zero for unsigned integers.
+        ///
+        /// Fails to compile if the range is empty.
+        impl<const UPPER: $inner> Default for $clamped_to<UPPER> {
+            fn default() -> Self {
+                const { assert!(Self::contains(<$inner>::MIN), "the range is empty") };
+                Self(<$inner>::MIN)
+            }
+        }
+
+        /// Yields just this value.
+        ///
+        /// See [`Self::iter`] for every value in the range.
+        impl<const UPPER: $inner> IntoIterator for $clamped_to<UPPER> {
+            type Item = Self;
+            type IntoIter = iter::Once<Self>;
+            fn into_iter(self) -> Self::IntoIter {
+                iter::once(self)
+            }
+        }
+
+        /// The static bounds of the type, `..UPPER`.
+        impl<const UPPER: $inner> ops::RangeBounds<$inner> for $clamped_to<UPPER> {
+            fn start_bound(&self) -> ops::Bound<&$inner> {
+                ops::Bound::Unbounded
+            }
+            fn end_bound(&self) -> ops::Bound<&$inner> {
+                ops::Bound::Excluded(&UPPER)
+            }
+        }
+
+        /// `..UPPER` is the same range as `MIN..UPPER`.
+        impl<const UPPER: $inner> From<$clamped<{ <$inner>::MIN }, UPPER>> for $clamped_to<UPPER> {
+            fn from(clamped: $clamped<{ <$inner>::MIN }, UPPER>) -> Self {
+                Self(clamped.0)
+            }
+        }
+
+        /// `..UPPER` is the same range as `MIN..UPPER`.
+        impl<const UPPER: $inner> From<$clamped_to<UPPER>> for $clamped<{ <$inner>::MIN }, UPPER> {
+            fn from(clamped: $clamped_to<UPPER>) -> Self {
+                Self(clamped.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<const UPPER: $inner> serde::Serialize for $clamped_to<UPPER> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, const UPPER: $inner> serde::Deserialize<'de> for $clamped_to<UPPER> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let inner = LenientVisitor::<$inner>::deserialize(deserializer)?;
+                Self::try_from(inner).map_err(serde::de::Error::custom)
+            }
+        }
+
+        /// The remainder is the inner integer, and is not re-validated against `..UPPER`.
+        ///
+        /// There is no `checked_rem` returning `Self`; use [`TryFrom`] on the remainder to re-validate.
+        impl<const UPPER: $inner> ops::Rem<$inner> for $clamped_to<UPPER> {
+            type Output = $inner;
+            /// # Panics
+            /// As for the inner integer, if `rhs` is zero, or for `MIN % -1`.
+            fn rem(self, rhs: $inner) -> $inner {
+                self.0 % rhs
+            }
+        }
+
+        impl<const UPPER: $inner> AsRef<$inner> for $clamped_to<UPPER> {
+            fn as_ref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<const UPPER: $inner> SaturatingFrom<$inner> for $clamped_to<UPPER> {
+            fn saturating_from(inner: $inner) -> Self {
+                Self::saturating_from(inner)
+            }
+        }
+
+        impl<const UPPER: $inner> sealed::Step for $clamped_to<UPPER> {
+            fn succ(self) -> Self {
+                Self(self.0 + 1)
+            }
+            fn pred(self) -> Self {
+                Self(self.0 - 1)
+            }
+            fn distance(self, to: Self) -> Option<usize> {
+                usize::try_from(to.0.wrapping_sub(self.0) as $unsigned).ok()
+            }
+        }
+
+        /// A single leading `+` is accepted, as for the inner integer.
+        impl<const UPPER: $inner> str::FromStr for $clamped_to<UPPER> {
+            type Err = ParseClampedError<<Self as TryFrom<$inner>>::Error>;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let inner = s.parse::<$inner>().map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+        }
+
+        impl<const UPPER: $inner> TryFrom<$inner> for $clamped_to<UPPER> {
+            type Error = OutOfBoundsTo<$inner>;
+            /// # Panics
+            /// In debug mode if the range is empty.
+            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
+                debug_assert!(UPPER > <$inner>::MIN);
+                if inner >= UPPER {
+                    Err(OutOfBoundsTo {
+                        upper: UPPER,
+                        given: inner,
+                    })
+                } else {
+                    Ok(Self(inner))
+                }
+            }
+        }
+
+        impl<const UPPER: $inner> From<$clamped_to<UPPER>> for $inner {
+            fn from(clamped: $clamped_to<UPPER>) -> $inner {
+                clamped.0
+            }
+        }
+
+        #[cfg(feature = "precise-debug")]
+        impl<const UPPER: $inner> fmt::Debug for $clamped_to<UPPER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let name = format!("{}<{}>", stringify!($clamped_to), UPPER);
+                f.debug_tuple(&name).field(&self.0).finish()
+            }
+        }
+
+        #[cfg(not(feature = "precise-debug"))]
+        impl<const UPPER: $inner> fmt::Debug for $clamped_to<UPPER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // #[derive(Debug)] doesn't preserve the const type parameters
+                // Use type_name instead, and strip the module path
+                let name = match any::type_name::<Self>().rsplit_once("::") {
+                    Some((_pre, post)) => post,
+                    None => any::type_name::<Self>(),
+                };
+                f.debug_tuple(name).field(&self.0).finish()
+            }
+        }
+
+        impl<const UPPER: $inner> PartialEq<$inner> for $clamped_to<UPPER> {
+            fn eq(&self, other: &$inner) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<const UPPER: $inner> PartialEq<$clamped_to<UPPER>> for $inner {
+            fn eq(&self, other: &$clamped_to<UPPER>) -> bool {
+                *self == other.0
+            }
+        }
+
+        /// An integer bound in the inclusive range above `..=UPPER`.
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $clamped_to_inclusive<const UPPER: $inner>($inner);
+
+        impl<const UPPER: $inner> $clamped_to_inclusive<UPPER> {
+            /// The inner integer.
+            pub const fn get(self) -> $inner {
+                self.0
+            }
+
+            /// The inner integer, identical to [`Self::get`].
+            pub const fn as_inner_copy(self) -> $inner {
+                self.0
+            }
+
+            /// Construct, performing the same validation as [`TryFrom`].
+            pub fn new(inner: $inner) -> Result<Self, <Self as TryFrom<$inner>>::Error> {
+                Self::try_from(inner)
+            }
+
+            /// Construct, naming the field in the error on failure.
+            pub fn try_new_named(
+                inner: $inner,
+                field: &'static str,
+            ) -> Result<Self, NamedOutOfBounds<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(inner).map_err(|source| NamedOutOfBounds { field, source })
+            }
+
+            /// [`PartialEq::eq`], usable in `const` contexts.
+            pub const fn const_eq(self, other: Self) -> bool {
+                self.0 == other.0
+            }
+
+            /// [`Ord::cmp`], usable in `const` contexts.
+            pub const fn const_cmp(self, other: Self) -> cmp::Ordering {
+                if self.0 < other.0 {
+                    cmp::Ordering::Less
+                } else if self.0 > other.0 {
+                    cmp::Ordering::Greater
+                } else {
+                    cmp::Ordering::Equal
+                }
+            }
+
+            /// Construct by wrapping `value` around the range, e.g. to hash into `UPPER + 1` buckets.
+            pub fn from_wrapping(value: $inner) -> Self {
+                let offset = value.wrapping_sub(<$inner>::MIN) as $unsigned;
+                match (UPPER.wrapping_sub(<$inner>::MIN) as $unsigned).checked_add(1) {
+                    Some(cardinality) => {
+                        Self(<$inner>::MIN.wrapping_add((offset % cardinality) as $inner))
+                    }
+                    // The range covers every value
+                    None => Self(value),
+                }
+            }
+
+            /// Construct, pinning values above `UPPER` down to `UPPER`.
+            ///
+            /// Usable in `const` contexts, to build tables of valid values from raw constants.
+            pub const fn clamp_const(inner: $inner) -> Self {
+                if inner > UPPER {
+                    Self(UPPER)
+                } else {
+                    Self(inner)
+                }
+            }
+
+            /// Construct, pinning values outside the range into it, as [`Self::clamp_const`].
+            pub const fn saturating_from(inner: $inner) -> Self {
+                Self::clamp_const(inner)
+            }
+
+            /// Step one closer to `target` (pinned into the range), for shrinking test cases.
+            ///
+            /// Returns [`None`] if already at `target`.
+            pub fn shrink_toward(self, target: $inner) -> Option<Self> {
+                let target = Self::saturating_from(target).0;
+                // `target` is in the range, so every value between it and `self` is too
+                match self.0.cmp(&target) {
+                    cmp::Ordering::Greater => Some(Self(self.0 - 1)),
+                    cmp::Ordering::Less => Some(Self(self.0 + 1)),
+                    cmp::Ordering::Equal => None,
+                }
+            }
+
+            /// Collect `values` into a sorted [`Vec`].
+            pub fn sorted(values: impl IntoIterator<Item = Self>) -> Vec<Self> {
+                let mut values = values.into_iter().collect::<Vec<_>>();
+                values.sort_unstable();
+                values
+            }
+
+            /// Decode from the little-endian bytes at the front of `bytes`.
+            pub fn try_from_le_slice(
+                bytes: &[u8],
+            ) -> Result<Self, DecodeError<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(<$inner>::from_le_bytes(Self::front(bytes)?))
+                    .map_err(DecodeError::OutOfBounds)
+            }
+
+            /// Decode from the big-endian bytes at the front of `bytes`.
+            pub fn try_from_be_slice(
+                bytes: &[u8],
+            ) -> Result<Self, DecodeError<<Self as TryFrom<$inner>>::Error>> {
+                Self::try_from(<$inner>::from_be_bytes(Self::front(bytes)?))
+                    .map_err(DecodeError::OutOfBounds)
+            }
+
+            fn front(
+                bytes: &[u8],
+            ) -> Result<[u8; mem::size_of::<$inner>()], DecodeError<<Self as TryFrom<$inner>>::Error>>
+            {
+                match bytes.first_chunk() {
+                    Some(front) => Ok(*front),
+                    None => Err(DecodeError::InsufficientBytes {
+                        needed: mem::size_of::<$inner>(),
+                        given: bytes.len(),
+                    }),
+                }
+            }
+
+            /// The bitwise complement, returning [`None`] if it is out of bounds.
+            pub fn checked_not(self) -> Option<Self> {
+                Self::try_from(!self.0).ok()
+            }
+
+            /// Convert a slice of exactly `N` elements, validating each one.
+            pub fn try_from_slice_array<const N: usize>(
+                inners: &[$inner],
+            ) -> Result<[Self; N], ArrayConvertError<<Self as TryFrom<$inner>>::Error>> {
+                let array = <[$inner; N]>::try_from(inners).map_err(|_| ArrayConvertError::Length {
+                    expected: N,
+                    given: inners.len(),
+                })?;
+                for (index, inner) in array.into_iter().enumerate() {
+                    Self::try_from(inner)
+                        .map_err(|source| ArrayConvertError::OutOfBounds { index, source })?;
+                }
+                Ok(array.map(Self))
+            }
+
+            /// Every value in the range, in ascending order.
+            pub fn iter() -> Iter<Self> {
+                Iter {
+                    remaining: Some((Self(<$inner>::MIN), Self(UPPER))),
+                }
+            }
+
+            /// The smallest of `values`, or [`None`] if there are none.
+            pub fn min_of(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+                values.into_iter().min()
+            }
+
+            /// The largest of `values`, or [`None`] if there are none.
+            pub fn max_of(values: impl IntoIterator<Item = Self>) -> Option<Self> {
+                values.into_iter().max()
+            }
+
+            /// Whether `inner` is in the range.
+            pub const fn contains(inner: $inner) -> bool {
+                inner <= UPPER
+            }
+
+            /// Every value from `start` onwards, in ascending order.
+            pub fn iter_from(start: Self) -> Iter<Self> {
+                Iter {
+                    remaining: Self::iter().remaining.map(|(_, back)| (start, back)),
+                }
+            }
+
+            /// Every value before `end`, in ascending order.
+            pub fn iter_to(end: Self) -> Iter<Self> {
+                Iter {
+                    remaining: Self::iter().remaining.and_then(|(front, _)| match front == end {
+                        true => None,
+                        false => Some((front, Self(end.0 - 1))),
+                    }),
+                }
+            }
+
+            /// Convert every value, or report every value that is out of bounds.
+            pub fn validate_all(
+                inners: &[$inner],
+            ) -> Result<Vec<Self>, Vec<(usize, <Self as TryFrom<$inner>>::Error)>> {
+                let mut oks = Vec::with_capacity(inners.len());
+                let mut errs = Vec::new();
+                for (index, inner) in inners.iter().enumerate() {
+                    match Self::try_from(*inner) {
+                        Ok(ok) => oks.push(ok),
+                        Err(err) => errs.push((index, err)),
+                    }
+                }
+                match errs.is_empty() {
+                    true => Ok(oks),
+                    false => Err(errs),
+                }
+            }
+
+            /// Round to the nearest integer, pinning the result into the range.
+            ///
+            /// NaN is mapped to the lowest value in the range.
+            pub fn from_f64_saturating(value: f64) -> Self {
+                match value.is_nan() {
+                    true => Self::saturating_from(<$inner>::MIN),
+                    // `as` saturates at the limits of the inner type
+                    false => Self::saturating_from(value.round() as $inner),
+                }
+            }
+
+            /// Pin into `min..=max` as well as the range, mirroring [`Ord::clamp`].
+            ///
+            /// # Panics
+            /// In debug mode if `min > max`, or if no value in the range is in `min..=max`.
+            pub fn clamp_to(self, min: $inner, max: $inner) -> Self {
+                debug_assert!(min <= max);
+                let lowest = Self::clamp_const(min).0;
+                debug_assert!(
+                    min <= lowest && lowest <= max,
+                    "no value in range is in {min:?}..={max:?}"
+                );
+                Self::clamp_const(self.0.max(min).min(max))
+            }
+
+            /// Re-validate against a new bound.
+            pub fn checked_rebound<const U2: $inner>(
+                self,
+            ) -> Result<$clamped_to_inclusive<U2>, OutOfBoundsToInclusive<$inner>> {
+                $clamped_to_inclusive::try_from(self.0)
+            }
+
+            /// Whether every value in this range is also in the range bounded by `U2`,
+            /// so that [`Self::checked_rebound`] always succeeds.
+            pub const fn is_subrange_of<const U2: $inner>() -> bool {
+                UPPER <= U2
+            }
+
+            /// The inner integer, for use with e.g. [`slice::sort_by_key`].
+            pub const fn sort_key(self) -> $inner {
+                self.0
+            }
+
+            /// Accumulate base-10 `digits`, each in `0..=9`, most significant first.
+            ///
+            /// Errors are as for [`FromStr`](str::FromStr), e.g. for empty input, or digits out of range.
+            pub fn from_digits(
+                digits: impl IntoIterator<Item = u8>,
+            ) -> Result<Self, ParseClampedError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = parse_digits::<$inner>(digits).map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+
+            /// Every value in ascending order, repeated endlessly.
+            ///
+            /// Yields nothing if the range is empty.
+            pub fn cycle() -> impl Iterator<Item = Self> {
+                Self::iter().cycle()
+            }
+
+            /// Tighten to the inclusive range `L..=UPPER`.
+            pub fn with_lower<const L: $inner>(
+                self,
+            ) -> Result<$clamped_inclusive<L, UPPER>, OutOfBoundsInclusive<$inner>> {
+                $clamped_inclusive::try_from(self.0)
+            }
+
+            /// Round to the nearest integer, pinning the result into the range.
+            ///
+            /// NaN is mapped to the lowest value in the range.
+            pub fn from_f32_saturating(value: f32) -> Self {
+                match value.is_nan() {
+                    true => Self::saturating_from(<$inner>::MIN),
+                    // `as` saturates at the limits of the inner type
+                    false => Self::saturating_from(value.round() as $inner),
+                }
+            }
+
+            /// The bitwise OR, returning [`None`] if it is out of bounds.
+            pub fn checked_union(self, other: Self) -> Option<Self> {
+                Self::new(self.0 | other.0).ok()
+            }
+
+            /// Parse, detecting a `0x`, `0o` or `0b` prefix after any sign, and defaulting to decimal.
+            pub fn from_str_auto_radix(
+                src: &str,
+            ) -> Result<Self, ParseClampedError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = parse_auto_radix(src, <$inner>::from_str_radix)
+                    .map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+
+            /// Every entry of `map`, over the whole range.
+            pub fn range_query<V>(
+                map: &collections::BTreeMap<Self, V>,
+            ) -> collections::btree_map::Range<'_, Self, V> {
+                // every key is in the range, and this avoids constructing an inverted range if it is empty
+                map.range(..)
+            }
+
+            /// Render with `sep` between each group of three digits, e.g. `1,000,000`.
+            #[cfg(feature = "format")]
+            pub fn to_grouped_string(self, sep: char) -> String {
+                group_digits(&self.0.to_string(), sep)
+            }
+
+            /// The value halfway between this and `other`, rounding toward this, without overflow.
+            pub const fn midpoint_with(self, other: Self) -> Self {
+                // both values are in the range, so anything between them is too
+                if self.0 <= other.0 {
+                    let half = (other.0.wrapping_sub(self.0) as $unsigned / 2) as $inner;
+                    Self(self.0.wrapping_add(half))
+                } else {
+                    let half = (self.0.wrapping_sub(other.0) as $unsigned / 2) as $inner;
+                    Self(self.0.wrapping_sub(half))
+                }
+            }
+        }
+
+        /// As for the inner integer.
+        impl<const UPPER: $inner> fmt::Display for $clamped_to_inclusive<UPPER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        /// As for [`FromStr`](str::FromStr).
+        impl<'a, const UPPER: $inner> TryFrom<&'a str> for $clamped_to_inclusive<UPPER> {
+            type Error = ParseClampedError<<Self as TryFrom<$inner>>::Error>;
+            fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        /// The lowest value, `MIN`, which is zero for unsigned integers.
+        impl<const UPPER: $inner> Default for $clamped_to_inclusive<UPPER> {
+            fn default() -> Self {
+                Self(<$inner>::MIN)
+            }
+        }
+
+        /// Yields just this value.
+        ///
+        /// See [`Self::iter`] for every value in the range.
+        impl<const UPPER: $inner> IntoIterator for $clamped_to_inclusive<UPPER> {
+            type Item = Self;
+            type IntoIter = iter::Once<Self>;
+            fn into_iter(self) -> Self::IntoIter {
+                iter::once(self)
+            }
+        }
+
+        /// The static bounds of the type, `..=UPPER`.
+        impl<const UPPER: $inner> ops::RangeBounds<$inner> for $clamped_to_inclusive<UPPER> {
+            fn start_bound(&self) -> ops::Bound<&$inner> {
+                ops::Bound::Unbounded
+            }
+            fn end_bound(&self) -> ops::Bound<&$inner> {
+                ops::Bound::Included(&UPPER)
+            }
+        }
+
+        /// `..=UPPER` is the same range as `MIN..=UPPER`.
+        impl<const UPPER: $inner> From<$clamped_inclusive<{ <$inner>::MIN }, UPPER>>
+            for $clamped_to_inclusive<UPPER>
+        {
+            fn from(clamped: $clamped_inclusive<{ <$inner>::MIN }, UPPER>) -> Self {
+                Self(clamped.0)
+            }
+        }
+
+        /// `..=UPPER` is the same range as `MIN..=UPPER`.
+        impl<const UPPER: $inner> From<$clamped_to_inclusive<UPPER>>
+            for $clamped_inclusive<{ <$inner>::MIN }, UPPER>
+        {
+            fn from(clamped: $clamped_to_inclusive<UPPER>) -> Self {
+                Self(clamped.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<const UPPER: $inner> serde::Serialize for $clamped_to_inclusive<UPPER> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, const UPPER: $inner> serde::Deserialize<'de> for $clamped_to_inclusive<UPPER> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let inner = LenientVisitor::<$inner>::deserialize(deserializer)?;
+                Self::try_from(inner).map_err(serde::de::Error::custom)
+            }
+        }
+
+        /// The remainder is the inner integer, and is not re-validated against `..=UPPER`.
+        ///
+        /// There is no `checked_rem` returning `Self`; use [`TryFrom`] on the remainder to re-validate.
+        impl<const UPPER: $inner> ops::Rem<$inner> for $clamped_to_inclusive<UPPER> {
+            type Output = $inner;
+            /// # Panics
+            /// As for the inner integer, if `rhs` is zero, or for `MIN % -1`.
+            fn rem(self, rhs: $inner) -> $inner {
+                self.0 % rhs
+            }
+        }
+
+        impl<const UPPER: $inner> AsRef<$inner> for $clamped_to_inclusive<UPPER> {
+            fn as_ref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<const UPPER: $inner> SaturatingFrom<$inner> for $clamped_to_inclusive<UPPER> {
+            fn saturating_from(inner: $inner) -> Self {
+                Self::saturating_from(inner)
+            }
+        }
+
+        impl<const UPPER: $inner> sealed::Step for $clamped_to_inclusive<UPPER> {
+            fn succ(self) -> Self {
+                Self(self.0 + 1)
+            }
+            fn pred(self) -> Self {
+                Self(self.0 - 1)
+            }
+            fn distance(self, to: Self) -> Option<usize> {
+                usize::try_from(to.0.wrapping_sub(self.0) as $unsigned).ok()
+            }
+        }
+
+        /// A single leading `+` is accepted, as for the inner integer.
+        impl<const UPPER: $inner> str::FromStr for $clamped_to_inclusive<UPPER> {
+            type Err = ParseClampedError<<Self as TryFrom<$inner>>::Error>;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let inner = s.parse::<$inner>().map_err(ParseClampedError::Parse)?;
+                Self::try_from(inner).map_err(ParseClampedError::OutOfBounds)
+            }
+        }
+
+        impl<const UPPER: $inner> TryFrom<$inner> for $clamped_to_inclusive<UPPER> {
+            type Error = OutOfBoundsToInclusive<$inner>;
+            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
+                // the range always contains `MIN`, so cannot be empty
+                if inner > UPPER {
+                    Err(OutOfBoundsToInclusive {
+                        upper: UPPER,
+                        given: inner,
+                    })
+                } else {
+                    Ok(Self(inner))
+                }
+            }
+        }
+
+        impl<const UPPER: $inner> From<$clamped_to_inclusive<UPPER>> for $inner {
+            fn from(clamped: $clamped_to_inclusive<UPPER>) -> $inner {
+                clamped.0
+            }
+        }
+
+        #[cfg(feature = "precise-debug")]
+        impl<const UPPER: $inner> fmt::Debug for $clamped_to_inclusive<UPPER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let name = format!("{}<{}>", stringify!($clamped_to_inclusive), UPPER);
+                f.debug_tuple(&name).field(&self.0).finish()
+            }
+        }
+
+        #[cfg(not(feature = "precise-debug"))]
+        impl<const UPPER: $inner> fmt::Debug for $clamped_to_inclusive<UPPER> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // #[derive(Debug)] doesn't preserve the const type parameters
+                // Use type_name instead, and strip the module path
+                let name = match any::type_name::<Self>().rsplit_once("::") {
+                    Some((_pre, post)) => post,
+                    None => any::type_name::<Self>(),
+                };
+                f.debug_tuple(name).field(&self.0).finish()
+            }
+        }
+
+        impl<const UPPER: $inner> PartialEq<$inner> for $clamped_to_inclusive<UPPER> {
+            fn eq(&self, other: &$inner) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<const UPPER: $inner> PartialEq<$clamped_to_inclusive<UPPER>> for $inner {
+            fn eq(&self, other: &$clamped_to_inclusive<UPPER>) -> bool {
+                *self == other.0
+            }
+        }
+
+        cross_kind_cmp!([const LOWER: $inner, const UPPER: $inner] $clamped<LOWER, UPPER>, [const L2: $inner] $clamped_from<L2>);
+        cross_kind_cmp!([const LOWER: $inner, const UPPER: $inner] $clamped<LOWER, UPPER>, [const L2: $inner, const U2: $inner] $clamped_inclusive<L2, U2>);
+        cross_kind_cmp!([const LOWER: $inner, const UPPER: $inner] $clamped<LOWER, UPPER>, [const U2: $inner] $clamped_to<U2>);
+        cross_kind_cmp!([const LOWER: $inner, const UPPER: $inner] $clamped<LOWER, UPPER>, [const U2: $inner] $clamped_to_inclusive<U2>);
+        cross_kind_cmp!([const LOWER: $inner] $clamped_from<LOWER>, [const L2: $inner, const U2: $inner] $clamped<L2, U2>);
+        cross_kind_cmp!([const LOWER: $inner] $clamped_from<LOWER>, [const L2: $inner, const U2: $inner] $clamped_inclusive<L2, U2>);
+        cross_kind_cmp!([const LOWER: $inner] $clamped_from<LOWER>, [const U2: $inner] $clamped_to<U2>);
+        cross_kind_cmp!([const LOWER: $inner] $clamped_from<LOWER>, [const U2: $inner] $clamped_to_inclusive<U2>);
+        cross_kind_cmp!([const LOWER: $inner, const UPPER: $inner] $clamped_inclusive<LOWER, UPPER>, [const L2: $inner, const U2: $inner] $clamped<L2, U2>);
+        cross_kind_cmp!([const LOWER: $inner, const UPPER: $inner] $clamped_inclusive<LOWER, UPPER>, [const L2: $inner] $clamped_from<L2>);
+        cross_kind_cmp!([const LOWER: $inner, const UPPER: $inner] $clamped_inclusive<LOWER, UPPER>, [const U2: $inner] $clamped_to<U2>);
+        cross_kind_cmp!([const LOWER: $inner, const UPPER: $inner] $clamped_inclusive<LOWER, UPPER>, [const U2: $inner] $clamped_to_inclusive<U2>);
+        cross_kind_cmp!([const UPPER: $inner] $clamped_to<UPPER>, [const L2: $inner, const U2: $inner] $clamped<L2, U2>);
+        cross_kind_cmp!([const UPPER: $inner] $clamped_to<UPPER>, [const L2: $inner] $clamped_from<L2>);
+        cross_kind_cmp!([const UPPER: $inner] $clamped_to<UPPER>, [const L2: $inner, const U2: $inner] $clamped_inclusive<L2, U2>);
+        cross_kind_cmp!([const UPPER: $inner] $clamped_to<UPPER>, [const U2: $inner] $clamped_to_inclusive<U2>);
+        cross_kind_cmp!([const UPPER: $inner] $clamped_to_inclusive<UPPER>, [const L2: $inner, const U2: $inner] $clamped<L2, U2>);
+        cross_kind_cmp!([const UPPER: $inner] $clamped_to_inclusive<UPPER>, [const L2: $inner] $clamped_from<L2>);
+        cross_kind_cmp!([const UPPER: $inner] $clamped_to_inclusive<UPPER>, [const L2: $inner, const U2: $inner] $clamped_inclusive<L2, U2>);
+        cross_kind_cmp!([const UPPER: $inner] $clamped_to_inclusive<UPPER>, [const U2: $inner] $clamped_to<U2>);
+    };
+}
+
+clamped!(
+    u8,
+    u8,
+    ClampedU8,
+    ClampedU8From,
+    ClampedU8Inclusive,
+    ClampedU8To,
+    ClampedU8ToInclusive,
+);
+clamped!(
+    u16,
+    u16,
+    ClampedU16,
+    ClampedU16From,
+    ClampedU16Inclusive,
+    ClampedU16To,
+    ClampedU16ToInclusive,
+);
+clamped!(
+    u32,
+    u32,
+    ClampedU32,
+    ClampedU32From,
+    ClampedU32Inclusive,
+    ClampedU32To,
+    ClampedU32ToInclusive,
+);
+clamped!(
+    u64,
+    u64,
+    ClampedU64,
+    ClampedU64From,
+    ClampedU64Inclusive,
+    ClampedU64To,
+    ClampedU64ToInclusive,
+);
+clamped!(
+    u128,
+    u128,
+    ClampedU128,
+    ClampedU128From,
+    ClampedU128Inclusive,
+    ClampedU128To,
+    ClampedU128ToInclusive,
+);
+clamped!(
+    usize,
+    usize,
+    ClampedUsize,
+    ClampedUsizeFrom,
+    ClampedUsizeInclusive,
+    ClampedUsizeTo,
+    ClampedUsizeToInclusive,
+);
+
+clamped!(
+    i8,
+    u8,
+    ClampedI8,
+    ClampedI8From,
+    ClampedI8Inclusive,
+    ClampedI8To,
+    ClampedI8ToInclusive,
+);
+clamped!(
+    i16,
+    u16,
+    ClampedI16,
+    ClampedI16From,
+    ClampedI16Inclusive,
+    ClampedI16To,
+    ClampedI16ToInclusive,
+);
+clamped!(
+    i32,
+    u32,
+    ClampedI32,
+    ClampedI32From,
+    ClampedI32Inclusive,
+    ClampedI32To,
+    ClampedI32ToInclusive,
+);
+clamped!(
+    i64,
+    u64,
+    ClampedI64,
+    ClampedI64From,
+    ClampedI64Inclusive,
+    ClampedI64To,
+    ClampedI64ToInclusive,
+);
+clamped!(
+    i128,
+    u128,
+    ClampedI128,
+    ClampedI128From,
+    ClampedI128Inclusive,
+    ClampedI128To,
+    ClampedI128ToInclusive,
+);
+clamped!(
+    isize,
+    usize,
+    ClampedIsize,
+    ClampedIsizeFrom,
+    ClampedIsizeInclusive,
+    ClampedIsizeTo,
+    ClampedIsizeToInclusive,
+);
+
+macro_rules! unsigned {
+    (
+        $inner:ty,
+        $clamped:ident,
+        $clamped_from:ident,
+        $clamped_inclusive:ident,
+        $clamped_to:ident,
+        $clamped_to_inclusive:ident $(,)?
+    ) => {
+        impl<const LOWER: $inner, const UPPER: $inner> $clamped<LOWER, UPPER> {
+            /// Whether the value is a power of two.
+            pub const fn is_power_of_two(self) -> bool {
+                self.0.is_power_of_two()
+            }
+
+            /// Round up to the next power of two, returning [`None`] if it is out of bounds.
+            pub fn checked_next_power_of_two(self) -> Option<Self> {
+                Self::try_from(self.0.checked_next_power_of_two()?).ok()
+            }
+
+            /// The greatest common divisor.
+            ///
+            /// This is a raw integer, as it is generally out of bounds.
+            pub const fn gcd(self, other: Self) -> $inner {
+                let (mut a, mut b) = (self.0, other.0);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            /// The least common multiple, returning [`None`] on overflow.
+            ///
+            /// This is a raw integer, as it is generally out of bounds.
+            pub const fn checked_lcm(self, other: Self) -> Option<$inner> {
+                match self.gcd(other) {
+                    0 => Some(0),
+                    gcd => (self.0 / gcd).checked_mul(other.0),
+                }
+            }
+        }
+
+        impl<const LOWER: $inner> $clamped_from<LOWER> {
+            /// Whether the value is a power of two.
+            pub const fn is_power_of_two(self) -> bool {
+                self.0.is_power_of_two()
+            }
+
+            /// Round up to the next power of two, returning [`None`] if it is out of bounds.
+            pub fn checked_next_power_of_two(self) -> Option<Self> {
+                Self::try_from(self.0.checked_next_power_of_two()?).ok()
+            }
+
+            /// The greatest common divisor.
+            ///
+            /// This is a raw integer, as it is generally out of bounds.
+            pub const fn gcd(self, other: Self) -> $inner {
+                let (mut a, mut b) = (self.0, other.0);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            /// The least common multiple, returning [`None`] on overflow.
+            ///
+            /// This is a raw integer, as it is generally out of bounds.
+            pub const fn checked_lcm(self, other: Self) -> Option<$inner> {
+                match self.gcd(other) {
+                    0 => Some(0),
+                    gcd => (self.0 / gcd).checked_mul(other.0),
+                }
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> $clamped_inclusive<LOWER, UPPER> {
+            /// Whether the value is a power of two.
+            pub const fn is_power_of_two(self) -> bool {
+                self.0.is_power_of_two()
+            }
+
+            /// Round up to the next power of two, returning [`None`] if it is out of bounds.
+            pub fn checked_next_power_of_two(self) -> Option<Self> {
+                Self::try_from(self.0.checked_next_power_of_two()?).ok()
+            }
+
+            /// The greatest common divisor.
+            ///
+            /// This is a raw integer, as it is generally out of bounds.
+            pub const fn gcd(self, other: Self) -> $inner {
+                let (mut a, mut b) = (self.0, other.0);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            /// The least common multiple, returning [`None`] on overflow.
+            ///
+            /// This is a raw integer, as it is generally out of bounds.
+            pub const fn checked_lcm(self, other: Self) -> Option<$inner> {
+                match self.gcd(other) {
+                    0 => Some(0),
+                    gcd => (self.0 / gcd).checked_mul(other.0),
+                }
+            }
+        }
+
+        impl<const UPPER: $inner> $clamped_to<UPPER> {
+            /// Whether the value is a power of two.
+            pub const fn is_power_of_two(self) -> bool {
+                self.0.is_power_of_two()
+            }
+
+            /// Round up to the next power of two, returning [`None`] if it is out of bounds.
+            pub fn checked_next_power_of_two(self) -> Option<Self> {
+                Self::try_from(self.0.checked_next_power_of_two()?).ok()
+            }
+
+            /// The greatest common divisor.
+            ///
+            /// This is a raw integer, as it is generally out of bounds.
+            pub const fn gcd(self, other: Self) -> $inner {
+                let (mut a, mut b) = (self.0, other.0);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            /// The least common multiple, returning [`None`] on overflow.
+            ///
+            /// This is a raw integer, as it is generally out of bounds.
+            pub const fn checked_lcm(self, other: Self) -> Option<$inner> {
+                match self.gcd(other) {
+                    0 => Some(0),
+                    gcd => (self.0 / gcd).checked_mul(other.0),
+                }
+            }
+        }
+
+        impl<const UPPER: $inner> $clamped_to_inclusive<UPPER> {
+            /// Whether the value is a power of two.
+            pub const fn is_power_of_two(self) -> bool {
+                self.0.is_power_of_two()
+            }
+
+            /// Round up to the next power of two, returning [`None`] if it is out of bounds.
+            pub fn checked_next_power_of_two(self) -> Option<Self> {
+                Self::try_from(self.0.checked_next_power_of_two()?).ok()
+            }
+
+            /// The greatest common divisor.
+            ///
+            /// This is a raw integer, as it is generally out of bounds.
+            pub const fn gcd(self, other: Self) -> $inner {
+                let (mut a, mut b) = (self.0, other.0);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            /// The least common multiple, returning [`None`] on overflow.
+            ///
+            /// This is a raw integer, as it is generally out of bounds.
+            pub const fn checked_lcm(self, other: Self) -> Option<$inner> {
+                match self.gcd(other) {
+                    0 => Some(0),
+                    gcd => (self.0 / gcd).checked_mul(other.0),
+                }
+            }
+        }
+    };
+}
+
+unsigned!(
+    u8,
+    ClampedU8,
+    ClampedU8From,
+    ClampedU8Inclusive,
+    ClampedU8To,
+    ClampedU8ToInclusive,
+);
+unsigned!(
+    u16,
+    ClampedU16,
+    ClampedU16From,
+    ClampedU16Inclusive,
+    ClampedU16To,
+    ClampedU16ToInclusive,
+);
+unsigned!(
+    u32,
+    ClampedU32,
+    ClampedU32From,
+    ClampedU32Inclusive,
+    ClampedU32To,
+    ClampedU32ToInclusive,
+);
+unsigned!(
+    u64,
+    ClampedU64,
+    ClampedU64From,
+    ClampedU64Inclusive,
+    ClampedU64To,
+    ClampedU64ToInclusive,
+);
+unsigned!(
+    u128,
+    ClampedU128,
+    ClampedU128From,
+    ClampedU128Inclusive,
+    ClampedU128To,
+    ClampedU128ToInclusive,
+);
+unsigned!(
+    usize,
+    ClampedUsize,
+    ClampedUsizeFrom,
+    ClampedUsizeInclusive,
+    ClampedUsizeTo,
+    ClampedUsizeToInclusive,
+);
+
+macro_rules! signed {
+    (
+        $inner:ty,
+        $clamped:ident,
+        $clamped_from:ident,
+        $clamped_inclusive:ident,
+        $clamped_to:ident,
+        $clamped_to_inclusive:ident $(,)?
+    ) => {
+        impl<const LOWER: $inner, const UPPER: $inner> $clamped<LOWER, UPPER> {
+            /// Add `delta`, pinning the result to the nearest bound.
+            ///
+            /// See [`Self::wrapping_add`] for cyclic behaviour.
+            pub fn saturating_add_signed(self, delta: $inner) -> Self {
+                Self::saturating_from(self.0.saturating_add(delta))
+            }
+
+            /// Add `delta`, returning [`None`] if the result is out of bounds.
+            pub fn checked_add_signed(self, delta: $inner) -> Option<Self> {
+                Self::try_from(self.0.checked_add(delta)?).ok()
+            }
+
+            /// `-1`, `0` or `1`, according to the sign of the value.
+            pub const fn signum(self) -> $inner {
+                self.0.signum()
+            }
+
+            /// Whether the range is symmetric about zero, so negation is always in bounds.
+            ///
+            /// A range containing `MIN` is never symmetric, since `MIN` cannot be negated.
+            pub const fn is_symmetric() -> bool {
+                matches!(
+                    (LOWER.checked_neg(), UPPER.checked_sub(1)),
+                    (Some(neg), Some(max)) if neg == max
+                )
+            }
+
+            /// Negate, without a [`Result`], for [symmetric](Self::is_symmetric) ranges.
+            ///
+            /// # Panics
+            /// If the range is not symmetric.
+            pub const fn neg_unchecked(self) -> Self {
+                assert!(Self::is_symmetric(), "the range is not symmetric");
+                Self(-self.0)
+            }
+        }
+
+        /// See [`neg_unchecked`](Self::neg_unchecked) for symmetric ranges.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Neg for $clamped<LOWER, UPPER> {
+            type Output = Result<Self, <Self as TryFrom<$inner>>::Error>;
+            fn neg(self) -> Self::Output {
+                match self.0.checked_neg() {
+                    Some(negated) => Self::try_from(negated),
+                    // `-MIN` doesn't fit in the inner type
+                    None => Err(OutOfBounds { lower: LOWER, upper: UPPER, given: self.0 }),
+                }
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> $clamped_inclusive<LOWER, UPPER> {
+            /// Add `delta`, pinning the result to the nearest bound.
+            ///
+            /// See [`Self::wrapping_add`] for cyclic behaviour.
+            pub fn saturating_add_signed(self, delta: $inner) -> Self {
+                Self::saturating_from(self.0.saturating_add(delta))
+            }
+
+            /// Add `delta`, returning [`None`] if the result is out of bounds.
+            pub fn checked_add_signed(self, delta: $inner) -> Option<Self> {
+                Self::try_from(self.0.checked_add(delta)?).ok()
+            }
+
+            /// `-1`, `0` or `1`, according to the sign of the value.
+            pub const fn signum(self) -> $inner {
+                self.0.signum()
+            }
+
+            /// Whether the range is symmetric about zero, so negation is always in bounds.
+            ///
+            /// A range containing `MIN` is never symmetric, since `MIN` cannot be negated.
+            pub const fn is_symmetric() -> bool {
+                matches!(LOWER.checked_neg(), Some(neg) if neg == UPPER)
+            }
+
+            /// Negate, without a [`Result`], for [symmetric](Self::is_symmetric) ranges.
+            ///
+            /// # Panics
+            /// If the range is not symmetric.
+            pub const fn neg_unchecked(self) -> Self {
+                assert!(Self::is_symmetric(), "the range is not symmetric");
+                Self(-self.0)
+            }
+        }
+
+        /// See [`neg_unchecked`](Self::neg_unchecked) for symmetric ranges.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Neg for $clamped_inclusive<LOWER, UPPER> {
+            type Output = Result<Self, <Self as TryFrom<$inner>>::Error>;
+            fn neg(self) -> Self::Output {
+                match self.0.checked_neg() {
+                    Some(negated) => Self::try_from(negated),
+                    // `-MIN` doesn't fit in the inner type
+                    None => Err(OutOfBoundsInclusive { lower: LOWER, upper: UPPER, given: self.0 }),
+                }
+            }
+        }
+
+        impl<const LOWER: $inner> $clamped_from<LOWER> {
+            /// `-1`, `0` or `1`, according to the sign of the value.
+            pub const fn signum(self) -> $inner {
+                self.0.signum()
+            }
+        }
+
+        impl<const LOWER: $inner> ops::Neg for $clamped_from<LOWER> {
+            type Output = Result<Self, <Self as TryFrom<$inner>>::Error>;
+            fn neg(self) -> Self::Output {
+                match self.0.checked_neg() {
+                    Some(negated) => Self::try_from(negated),
+                    // `-MIN` doesn't fit in the inner type
+                    None => Err(OutOfBoundsFrom { lower: LOWER, given: self.0 }),
+                }
+            }
+        }
+
+        impl<const UPPER: $inner> $clamped_to<UPPER> {
+            /// `-1`, `0` or `1`, according to the sign of the value.
+            pub const fn signum(self) -> $inner {
+                self.0.signum()
+            }
+        }
+
+        impl<const UPPER: $inner> ops::Neg for $clamped_to<UPPER> {
+            type Output = Result<Self, <Self as TryFrom<$inner>>::Error>;
+            fn neg(self) -> Self::Output {
+                match self.0.checked_neg() {
+                    Some(negated) => Self::try_from(negated),
+                    // `-MIN` doesn't fit in the inner type
+                    None => Err(OutOfBoundsTo { upper: UPPER, given: self.0 }),
+                }
+            }
+        }
+
+        impl<const UPPER: $inner> $clamped_to_inclusive<UPPER> {
+            /// `-1`, `0` or `1`, according to the sign of the value.
+            pub const fn signum(self) -> $inner {
+                self.0.signum()
+            }
+        }
+
+        impl<const UPPER: $inner> ops::Neg for $clamped_to_inclusive<UPPER> {
+            type Output = Result<Self, <Self as TryFrom<$inner>>::Error>;
+            fn neg(self) -> Self::Output {
+                match self.0.checked_neg() {
+                    Some(negated) => Self::try_from(negated),
+                    // `-MIN` doesn't fit in the inner type
+                    None => Err(OutOfBoundsToInclusive { upper: UPPER, given: self.0 }),
+                }
+            }
+        }
+    };
+}
+
+signed!(
+    i8,
+    ClampedI8,
+    ClampedI8From,
+    ClampedI8Inclusive,
+    ClampedI8To,
+    ClampedI8ToInclusive,
+);
+signed!(
+    i16,
+    ClampedI16,
+    ClampedI16From,
+    ClampedI16Inclusive,
+    ClampedI16To,
+    ClampedI16ToInclusive,
+);
+signed!(
+    i32,
+    ClampedI32,
+    ClampedI32From,
+    ClampedI32Inclusive,
+    ClampedI32To,
+    ClampedI32ToInclusive,
+);
+signed!(
+    i64,
+    ClampedI64,
+    ClampedI64From,
+    ClampedI64Inclusive,
+    ClampedI64To,
+    ClampedI64ToInclusive,
+);
+signed!(
+    i128,
+    ClampedI128,
+    ClampedI128From,
+    ClampedI128Inclusive,
+    ClampedI128To,
+    ClampedI128ToInclusive,
+);
+signed!(
+    isize,
+    ClampedIsize,
+    ClampedIsizeFrom,
+    ClampedIsizeInclusive,
+    ClampedIsizeTo,
+    ClampedIsizeToInclusive,
+);
+
+// Items relating the unsigned and signed types of the same width.
+macro_rules! sign_pair {
+    (
+        $unsigned_inner:ty,
+        $unsigned:ident,
+        $unsigned_from:ident,
+        $unsigned_inclusive:ident,
+        $unsigned_to:ident,
+        $unsigned_to_inclusive:ident;
+        $signed_inner:ty,
+        $signed:ident,
+        $signed_from:ident,
+        $signed_inclusive:ident,
+        $signed_to:ident,
+        $signed_to_inclusive:ident $(;)?
+    ) => {
+        impl<const LOWER: $unsigned_inner, const UPPER: $unsigned_inner> $unsigned<LOWER, UPPER> {
+            /// Reinterpret the bits as the signed type, and re-validate against its bounds.
+            ///
+            /// Values above the signed maximum become negative, e.g. `0x80` becomes `-128`.
+            pub fn try_as_signed<const L2: $signed_inner, const U2: $signed_inner>(
+                self,
+            ) -> Result<$signed<L2, U2>, <$signed<L2, U2> as TryFrom<$signed_inner>>::Error> {
+                $signed::try_from(self.0 as $signed_inner)
+            }
+        }
+
+        impl<const LOWER: $unsigned_inner> $unsigned_from<LOWER> {
+            /// Reinterpret the bits as the signed type, and re-validate against its bounds.
+            ///
+            /// Values above the signed maximum become negative, e.g. `0x80` becomes `-128`.
+            pub fn try_as_signed<const L2: $signed_inner>(
+                self,
+            ) -> Result<$signed_from<L2>, <$signed_from<L2> as TryFrom<$signed_inner>>::Error> {
+                $signed_from::try_from(self.0 as $signed_inner)
+            }
+        }
+
+        impl<const LOWER: $unsigned_inner, const UPPER: $unsigned_inner>
+            $unsigned_inclusive<LOWER, UPPER>
+        {
+            /// Reinterpret the bits as the signed type, and re-validate against its bounds.
+            ///
+            /// Values above the signed maximum become negative, e.g. `0x80` becomes `-128`.
+            pub fn try_as_signed<const L2: $signed_inner, const U2: $signed_inner>(
+                self,
+            ) -> Result<
+                $signed_inclusive<L2, U2>,
+                <$signed_inclusive<L2, U2> as TryFrom<$signed_inner>>::Error,
+            > {
+                $signed_inclusive::try_from(self.0 as $signed_inner)
+            }
+        }
+
+        impl<const UPPER: $unsigned_inner> $unsigned_to<UPPER> {
+            /// Reinterpret the bits as the signed type, and re-validate against its bounds.
+            ///
+            /// Values above the signed maximum become negative, e.g. `0x80` becomes `-128`.
+            pub fn try_as_signed<const U2: $signed_inner>(
+                self,
+            ) -> Result<$signed_to<U2>, <$signed_to<U2> as TryFrom<$signed_inner>>::Error> {
+                $signed_to::try_from(self.0 as $signed_inner)
+            }
+        }
+
+        impl<const UPPER: $unsigned_inner> $unsigned_to_inclusive<UPPER> {
+            /// Reinterpret the bits as the signed type, and re-validate against its bounds.
+            ///
+            /// Values above the signed maximum become negative, e.g. `0x80` becomes `-128`.
+            pub fn try_as_signed<const U2: $signed_inner>(
+                self,
+            ) -> Result<
+                $signed_to_inclusive<U2>,
+                <$signed_to_inclusive<U2> as TryFrom<$signed_inner>>::Error,
+            > {
+                $signed_to_inclusive::try_from(self.0 as $signed_inner)
+            }
+        }
+
+        impl<const LOWER: $signed_inner, const UPPER: $signed_inner> $signed<LOWER, UPPER> {
+            /// Reinterpret the bits as the unsigned type, and re-validate against its bounds.
+            ///
+            /// Negative values become large, e.g. `-128` becomes `0x80`.
+            pub fn try_as_unsigned<const L2: $unsigned_inner, const U2: $unsigned_inner>(
+                self,
+            ) -> Result<$unsigned<L2, U2>, <$unsigned<L2, U2> as TryFrom<$unsigned_inner>>::Error>
+            {
+                $unsigned::try_from(self.0 as $unsigned_inner)
+            }
+        }
+
+        impl<const LOWER: $signed_inner> $signed_from<LOWER> {
+            /// Reinterpret the bits as the unsigned type, and re-validate against its bounds.
+            ///
+            /// Negative values become large, e.g. `-128` becomes `0x80`.
+            pub fn try_as_unsigned<const L2: $unsigned_inner>(
+                self,
+            ) -> Result<$unsigned_from<L2>, <$unsigned_from<L2> as TryFrom<$unsigned_inner>>::Error>
+            {
+                $unsigned_from::try_from(self.0 as $unsigned_inner)
+            }
+        }
+
+        impl<const LOWER: $signed_inner, const UPPER: $signed_inner>
+            $signed_inclusive<LOWER, UPPER>
+        {
+            /// Reinterpret the bits as the unsigned type, and re-validate against its bounds.
+            ///
+            /// Negative values become large, e.g. `-128` becomes `0x80`.
+            pub fn try_as_unsigned<const L2: $unsigned_inner, const U2: $unsigned_inner>(
+                self,
+            ) -> Result<
+                $unsigned_inclusive<L2, U2>,
+                <$unsigned_inclusive<L2, U2> as TryFrom<$unsigned_inner>>::Error,
+            > {
+                $unsigned_inclusive::try_from(self.0 as $unsigned_inner)
+            }
+        }
+
+        impl<const UPPER: $signed_inner> $signed_to<UPPER> {
+            /// Reinterpret the bits as the unsigned type, and re-validate against its bounds.
+            ///
+            /// Negative values become large, e.g. `-128` becomes `0x80`.
+            pub fn try_as_unsigned<const U2: $unsigned_inner>(
+                self,
+            ) -> Result<$unsigned_to<U2>, <$unsigned_to<U2> as TryFrom<$unsigned_inner>>::Error>
+            {
+                $unsigned_to::try_from(self.0 as $unsigned_inner)
+            }
+        }
+
+        impl<const UPPER: $signed_inner> $signed_to_inclusive<UPPER> {
+            /// Reinterpret the bits as the unsigned type, and re-validate against its bounds.
+            ///
+            /// Negative values become large, e.g. `-128` becomes `0x80`.
+            pub fn try_as_unsigned<const U2: $unsigned_inner>(
+                self,
+            ) -> Result<
+                $unsigned_to_inclusive<U2>,
+                <$unsigned_to_inclusive<U2> as TryFrom<$unsigned_inner>>::Error,
+            > {
+                $unsigned_to_inclusive::try_from(self.0 as $unsigned_inner)
+            }
+        }
+
+        impl<const LOWER: $unsigned_inner, const UPPER: $unsigned_inner> $unsigned<LOWER, UPPER> {
+            /// The absolute difference between the values, re-validated against the given bounds.
+            pub fn abs_diff_in<const L2: $unsigned_inner, const U2: $unsigned_inner>(
+                self,
+                other: Self,
+            ) -> Result<$unsigned<L2, U2>, OutOfBounds<$unsigned_inner>> {
+                $unsigned::try_from(self.0.abs_diff(other.0))
+            }
+        }
+
+        impl<const LOWER: $unsigned_inner, const UPPER: $unsigned_inner>
+            $unsigned_inclusive<LOWER, UPPER>
+        {
+            /// The absolute difference between the values, re-validated against the given bounds.
+            pub fn abs_diff_in<const L2: $unsigned_inner, const U2: $unsigned_inner>(
+                self,
+                other: Self,
+            ) -> Result<$unsigned<L2, U2>, OutOfBounds<$unsigned_inner>> {
+                $unsigned::try_from(self.0.abs_diff(other.0))
+            }
+        }
+
+        impl<const LOWER: $signed_inner, const UPPER: $signed_inner> $signed<LOWER, UPPER> {
+            /// The absolute difference between the values, re-validated against the given bounds.
+            pub fn abs_diff_in<const L2: $unsigned_inner, const U2: $unsigned_inner>(
+                self,
+                other: Self,
+            ) -> Result<$unsigned<L2, U2>, OutOfBounds<$unsigned_inner>> {
+                $unsigned::try_from(self.0.abs_diff(other.0))
+            }
+        }
+
+        impl<const LOWER: $signed_inner, const UPPER: $signed_inner>
+            $signed_inclusive<LOWER, UPPER>
+        {
+            /// The absolute difference between the values, re-validated against the given bounds.
+            pub fn abs_diff_in<const L2: $unsigned_inner, const U2: $unsigned_inner>(
+                self,
+                other: Self,
+            ) -> Result<$unsigned<L2, U2>, OutOfBounds<$unsigned_inner>> {
+                $unsigned::try_from(self.0.abs_diff(other.0))
+            }
+        }
+    };
+}
+
+sign_pair!(
+    u8,
+    ClampedU8,
+    ClampedU8From,
+    ClampedU8Inclusive,
+    ClampedU8To,
+    ClampedU8ToInclusive;
+    i8,
+    ClampedI8,
+    ClampedI8From,
+    ClampedI8Inclusive,
+    ClampedI8To,
+    ClampedI8ToInclusive;
+);
+sign_pair!(
+    u16,
+    ClampedU16,
+    ClampedU16From,
+    ClampedU16Inclusive,
+    ClampedU16To,
+    ClampedU16ToInclusive;
+    i16,
+    ClampedI16,
+    ClampedI16From,
+    ClampedI16Inclusive,
+    ClampedI16To,
+    ClampedI16ToInclusive;
+);
+sign_pair!(
+    u32,
+    ClampedU32,
+    ClampedU32From,
+    ClampedU32Inclusive,
+    ClampedU32To,
+    ClampedU32ToInclusive;
+    i32,
+    ClampedI32,
+    ClampedI32From,
+    ClampedI32Inclusive,
+    ClampedI32To,
+    ClampedI32ToInclusive;
+);
+sign_pair!(
+    u64,
+    ClampedU64,
+    ClampedU64From,
+    ClampedU64Inclusive,
+    ClampedU64To,
+    ClampedU64ToInclusive;
+    i64,
+    ClampedI64,
+    ClampedI64From,
+    ClampedI64Inclusive,
+    ClampedI64To,
+    ClampedI64ToInclusive;
+);
+sign_pair!(
+    u128,
+    ClampedU128,
+    ClampedU128From,
+    ClampedU128Inclusive,
+    ClampedU128To,
+    ClampedU128ToInclusive;
+    i128,
+    ClampedI128,
+    ClampedI128From,
+    ClampedI128Inclusive,
+    ClampedI128To,
+    ClampedI128ToInclusive;
+);
+sign_pair!(
+    usize,
+    ClampedUsize,
+    ClampedUsizeFrom,
+    ClampedUsizeInclusive,
+    ClampedUsizeTo,
+    ClampedUsizeToInclusive;
+    isize,
+    ClampedIsize,
+    ClampedIsizeFrom,
+    ClampedIsizeInclusive,
+    ClampedIsizeTo,
+    ClampedIsizeToInclusive;
+);
+
+macro_rules! into_i128 {
+    (
+        $inner:ty,
+        $clamped:ident,
+        $clamped_from:ident,
+        $clamped_inclusive:ident,
+        $clamped_to:ident,
+        $clamped_to_inclusive:ident $(,)?
+    ) => {
+        impl<const LOWER: $inner, const UPPER: $inner> From<$clamped<LOWER, UPPER>> for i128 {
+            fn from(clamped: $clamped<LOWER, UPPER>) -> i128 {
+                clamped.0 as i128
+            }
+        }
+
+        impl<const LOWER: $inner> From<$clamped_from<LOWER>> for i128 {
+            fn from(clamped: $clamped_from<LOWER>) -> i128 {
+                clamped.0 as i128
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> From<$clamped_inclusive<LOWER, UPPER>>
+            for i128
+        {
+            fn from(clamped: $clamped_inclusive<LOWER, UPPER>) -> i128 {
+                clamped.0 as i128
+            }
+        }
+
+        impl<const UPPER: $inner> From<$clamped_to<UPPER>> for i128 {
+            fn from(clamped: $clamped_to<UPPER>) -> i128 {
+                clamped.0 as i128
+            }
+        }
+
+        impl<const UPPER: $inner> From<$clamped_to_inclusive<UPPER>> for i128 {
+            fn from(clamped: $clamped_to_inclusive<UPPER>) -> i128 {
+                clamped.0 as i128
+            }
+        }
+    };
+}
+
+// Every inner type except u128 fits losslessly in an i128.
+// (i128 itself is covered by clamped!)
+into_i128!(
+    u8,
+    ClampedU8,
+    ClampedU8From,
+    ClampedU8Inclusive,
+    ClampedU8To,
+    ClampedU8ToInclusive,
+);
+into_i128!(
+    u16,
+    ClampedU16,
+    ClampedU16From,
+    ClampedU16Inclusive,
+    ClampedU16To,
+    ClampedU16ToInclusive,
+);
+into_i128!(
+    u32,
+    ClampedU32,
+    ClampedU32From,
+    ClampedU32Inclusive,
+    ClampedU32To,
+    ClampedU32ToInclusive,
+);
+into_i128!(
+    u64,
+    ClampedU64,
+    ClampedU64From,
+    ClampedU64Inclusive,
+    ClampedU64To,
+    ClampedU64ToInclusive,
+);
+into_i128!(
+    usize,
+    ClampedUsize,
+    ClampedUsizeFrom,
+    ClampedUsizeInclusive,
+    ClampedUsizeTo,
+    ClampedUsizeToInclusive,
+);
+
+into_i128!(
+    i8,
+    ClampedI8,
+    ClampedI8From,
+    ClampedI8Inclusive,
+    ClampedI8To,
+    ClampedI8ToInclusive,
+);
+into_i128!(
+    i16,
+    ClampedI16,
+    ClampedI16From,
+    ClampedI16Inclusive,
+    ClampedI16To,
+    ClampedI16ToInclusive,
+);
+into_i128!(
+    i32,
+    ClampedI32,
+    ClampedI32From,
+    ClampedI32Inclusive,
+    ClampedI32To,
+    ClampedI32ToInclusive,
+);
+into_i128!(
+    i64,
+    ClampedI64,
+    ClampedI64From,
+    ClampedI64Inclusive,
+    ClampedI64To,
+    ClampedI64ToInclusive,
+);
+into_i128!(
+    isize,
+    ClampedIsize,
+    ClampedIsizeFrom,
+    ClampedIsizeInclusive,
+    ClampedIsizeTo,
+    ClampedIsizeToInclusive,
+);
+
+macro_rules! nohash {
+    (
+        $inner:ty,
+        $clamped:ident,
+        $clamped_from:ident,
+        $clamped_inclusive:ident,
+        $clamped_to:ident,
+        $clamped_to_inclusive:ident $(,)?
+    ) => {
+        #[cfg(feature = "nohash-hasher")]
+        impl<const LOWER: $inner, const UPPER: $inner> nohash_hasher::IsEnabled
+            for $clamped<LOWER, UPPER>
+        {
+        }
+
+        #[cfg(feature = "nohash-hasher")]
+        impl<const LOWER: $inner> nohash_hasher::IsEnabled for $clamped_from<LOWER> {}
+
+        #[cfg(feature = "nohash-hasher")]
+        impl<const LOWER: $inner, const UPPER: $inner> nohash_hasher::IsEnabled
+            for $clamped_inclusive<LOWER, UPPER>
+        {
+        }
+
+        #[cfg(feature = "nohash-hasher")]
+        impl<const UPPER: $inner> nohash_hasher::IsEnabled for $clamped_to<UPPER> {}
+
+        #[cfg(feature = "nohash-hasher")]
+        impl<const UPPER: $inner> nohash_hasher::IsEnabled for $clamped_to_inclusive<UPPER> {}
+    };
+}
+
+// The derived `Hash` writes just the inner integer, which `nohash_hasher` supports up to 64 bits.
+nohash!(
+    u8,
+    ClampedU8,
+    ClampedU8From,
+    ClampedU8Inclusive,
+    ClampedU8To,
+    ClampedU8ToInclusive,
+);
+nohash!(
+    u16,
+    ClampedU16,
+    ClampedU16From,
+    ClampedU16Inclusive,
+    ClampedU16To,
+    ClampedU16ToInclusive,
+);
+nohash!(
+    u32,
+    ClampedU32,
+    ClampedU32From,
+    ClampedU32Inclusive,
+    ClampedU32To,
+    ClampedU32ToInclusive,
+);
+nohash!(
+    u64,
+    ClampedU64,
+    ClampedU64From,
+    ClampedU64Inclusive,
+    ClampedU64To,
+    ClampedU64ToInclusive,
+);
+nohash!(
+    usize,
+    ClampedUsize,
+    ClampedUsizeFrom,
+    ClampedUsizeInclusive,
+    ClampedUsizeTo,
+    ClampedUsizeToInclusive,
+);
+
+nohash!(
+    i8,
+    ClampedI8,
+    ClampedI8From,
+    ClampedI8Inclusive,
+    ClampedI8To,
+    ClampedI8ToInclusive,
+);
+nohash!(
+    i16,
+    ClampedI16,
+    ClampedI16From,
+    ClampedI16Inclusive,
+    ClampedI16To,
+    ClampedI16ToInclusive,
+);
+nohash!(
+    i32,
+    ClampedI32,
+    ClampedI32From,
+    ClampedI32Inclusive,
+    ClampedI32To,
+    ClampedI32ToInclusive,
+);
+nohash!(
+    i64,
+    ClampedI64,
+    ClampedI64From,
+    ClampedI64Inclusive,
+    ClampedI64To,
+    ClampedI64ToInclusive,
+);
+nohash!(
+    isize,
+    ClampedIsize,
+    ClampedIsizeFrom,
+    ClampedIsizeInclusive,
+    ClampedIsizeTo,
+    ClampedIsizeToInclusive,
+);
+
+// A `TryFrom` implementation would make calls like `ClampedU8::try_from(1)` ambiguous.
+macro_rules! narrow_fn {
+    (
+        $wide:ty => $inner:ty,
+        $name:ident,
+        $clamped:ident,
+        $clamped_from:ident,
+        $clamped_inclusive:ident,
+        $clamped_to:ident,
+        $clamped_to_inclusive:ident $(,)?
+    ) => {
+        impl<const LOWER: $inner, const UPPER: $inner> $clamped<LOWER, UPPER> {
+            /// Convert from a wider integer, checking that it fits, and is in bounds.
+            pub fn $name(
+                wide: $wide,
+            ) -> Result<Self, NarrowError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = <$inner>::try_from(wide).map_err(NarrowError::DoesNotFit)?;
+                Self::try_from(inner).map_err(NarrowError::OutOfBounds)
+            }
+        }
+
+        impl<const LOWER: $inner> $clamped_from<LOWER> {
+            /// Convert from a wider integer, checking that it fits, and is in bounds.
+            pub fn $name(
+                wide: $wide,
+            ) -> Result<Self, NarrowError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = <$inner>::try_from(wide).map_err(NarrowError::DoesNotFit)?;
+                Self::try_from(inner).map_err(NarrowError::OutOfBounds)
+            }
+        }
+
+        impl<const LOWER: $inner, const UPPER: $inner> $clamped_inclusive<LOWER, UPPER> {
+            /// Convert from a wider integer, checking that it fits, and is in bounds.
+            pub fn $name(
+                wide: $wide,
+            ) -> Result<Self, NarrowError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = <$inner>::try_from(wide).map_err(NarrowError::DoesNotFit)?;
+                Self::try_from(inner).map_err(NarrowError::OutOfBounds)
+            }
+        }
+
+        impl<const UPPER: $inner> $clamped_to<UPPER> {
+            /// Convert from a wider integer, checking that it fits, and is in bounds.
+            pub fn $name(
+                wide: $wide,
+            ) -> Result<Self, NarrowError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = <$inner>::try_from(wide).map_err(NarrowError::DoesNotFit)?;
+                Self::try_from(inner).map_err(NarrowError::OutOfBounds)
+            }
+        }
+
+        impl<const UPPER: $inner> $clamped_to_inclusive<UPPER> {
+            /// Convert from a wider integer, checking that it fits, and is in bounds.
+            pub fn $name(
+                wide: $wide,
+            ) -> Result<Self, NarrowError<<Self as TryFrom<$inner>>::Error>> {
+                let inner = <$inner>::try_from(wide).map_err(NarrowError::DoesNotFit)?;
+                Self::try_from(inner).map_err(NarrowError::OutOfBounds)
+            }
+        }
+    };
+}
+
+// usize varies in width between platforms
+narrow_fn!(
+    u32 => usize,
+    try_from_u32,
+    ClampedUsize,
+    ClampedUsizeFrom,
+    ClampedUsizeInclusive,
+    ClampedUsizeTo,
+    ClampedUsizeToInclusive,
+);
+narrow_fn!(
+    u64 => usize,
+    try_from_u64,
+    ClampedUsize,
+    ClampedUsizeFrom,
+    ClampedUsizeInclusive,
+    ClampedUsizeTo,
+    ClampedUsizeToInclusive,
+);
+
+// 64-bit integers are common at API boundaries, e.g. JSON and databases
+narrow_fn!(
+    u64 => u8,
+    try_from_u64,
+    ClampedU8,
+    ClampedU8From,
+    ClampedU8Inclusive,
+    ClampedU8To,
+    ClampedU8ToInclusive,
+);
+narrow_fn!(
+    u64 => u16,
+    try_from_u64,
+    ClampedU16,
+    ClampedU16From,
+    ClampedU16Inclusive,
+    ClampedU16To,
+    ClampedU16ToInclusive,
+);
+narrow_fn!(
+    u64 => u32,
+    try_from_u64,
+    ClampedU32,
+    ClampedU32From,
+    ClampedU32Inclusive,
+    ClampedU32To,
+    ClampedU32ToInclusive,
+);
+
+narrow_fn!(
+    i64 => i8,
+    try_from_i64,
+    ClampedI8,
+    ClampedI8From,
+    ClampedI8Inclusive,
+    ClampedI8To,
+    ClampedI8ToInclusive,
+);
+narrow_fn!(
+    i64 => i16,
+    try_from_i64,
+    ClampedI16,
+    ClampedI16From,
+    ClampedI16Inclusive,
+    ClampedI16To,
+    ClampedI16ToInclusive,
+);
+narrow_fn!(
+    i64 => i32,
+    try_from_i64,
+    ClampedI32,
+    ClampedI32From,
+    ClampedI32Inclusive,
+    ClampedI32To,
+    ClampedI32ToInclusive,
+);
+narrow_fn!(
+    i64 => isize,
+    try_from_i64,
+    ClampedIsize,
+    ClampedIsizeFrom,
+    ClampedIsizeInclusive,
+    ClampedIsizeTo,
+    ClampedIsizeToInclusive,
+);
+
+// The length of every range of these types fits in a usize, which is at least 16 bits.
+// As for std's ranges, 16-bit ranges may have 65536 values, so they only get the exclusive kinds.
+macro_rules! exact_size {
+    ($inner:ty, $clamped:ident, $clamped_to:ident $(,)?) => {
+        impl<const LOWER: $inner, const UPPER: $inner> ExactSizeIterator
+            for Iter<$clamped<LOWER, UPPER>>
+        {
+        }
+        impl<const UPPER: $inner> ExactSizeIterator for Iter<$clamped_to<UPPER>> {}
+    };
+    (
+        $inner:ty,
+        $clamped:ident,
+        $clamped_from:ident,
+        $clamped_inclusive:ident,
+        $clamped_to:ident,
+        $clamped_to_inclusive:ident $(,)?
+    ) => {
+        exact_size!($inner, $clamped, $clamped_to);
+        impl<const LOWER: $inner> ExactSizeIterator for Iter<$clamped_from<LOWER>> {}
+        impl<const LOWER: $inner, const UPPER: $inner> ExactSizeIterator
+            for Iter<$clamped_inclusive<LOWER, UPPER>>
+        {
+        }
+        impl<const UPPER: $inner> ExactSizeIterator for Iter<$clamped_to_inclusive<UPPER>> {}
+    };
+}
+
+exact_size!(
+    u8,
+    ClampedU8,
+    ClampedU8From,
+    ClampedU8Inclusive,
+    ClampedU8To,
+    ClampedU8ToInclusive,
+);
+exact_size!(u16, ClampedU16, ClampedU16To);
+exact_size!(
+    i8,
+    ClampedI8,
+    ClampedI8From,
+    ClampedI8Inclusive,
+    ClampedI8To,
+    ClampedI8ToInclusive,
+);
+exact_size!(i16, ClampedI16, ClampedI16To);
+
+macro_rules! duration {
+    ($inner:ty, $clamped:ident $(,)?) => {
+        impl<const LOWER: $inner, const UPPER: $inner> $clamped<LOWER, UPPER> {
+            /// The whole number of seconds in `duration`, checking that it is in bounds.
+            ///
+            /// Any fractional part is discarded.
+            pub fn try_from_secs(duration: time::Duration) -> Result<Self, OutOfBounds<$inner>> {
+                // `UPPER` is exclusive, so a saturated value is always out of bounds
+                Self::try_from(<$inner>::try_from(duration.as_secs()).unwrap_or(<$inner>::MAX))
+            }
+
+            /// This many seconds.
+            pub fn to_duration(self) -> time::Duration {
+                time::Duration::from_secs(self.0 as u64)
+            }
+        }
+    };
+}
+
+duration!(u32, ClampedU32);
+duration!(u64, ClampedU64);
+
+macro_rules! sub_delta {
+    ($inner:ty => $delta:ty, $clamped:ident, $clamped_inclusive:ident $(,)?) => {
+        /// Subtracting two values gives the signed difference between them, not another clamped value.
+        ///
+        /// The difference is widened, so it always fits.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Sub for $clamped<LOWER, UPPER> {
+            type Output = $delta;
+            fn sub(self, rhs: Self) -> $delta {
+                self.0 as $delta - rhs.0 as $delta
+            }
+        }
+
+        /// Subtracting two values gives the signed difference between them, not another clamped value.
+        ///
+        /// The difference is widened, so it always fits.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Sub
+            for $clamped_inclusive<LOWER, UPPER>
+        {
+            type Output = $delta;
+            fn sub(self, rhs: Self) -> $delta {
+                self.0 as $delta - rhs.0 as $delta
+            }
+        }
+    };
+}
+
+// There's no wider type for the differences of 128-bit integers.
+sub_delta!(u8 => i16, ClampedU8, ClampedU8Inclusive);
+sub_delta!(u16 => i32, ClampedU16, ClampedU16Inclusive);
+sub_delta!(u32 => i64, ClampedU32, ClampedU32Inclusive);
+sub_delta!(u64 => i128, ClampedU64, ClampedU64Inclusive);
+sub_delta!(usize => i128, ClampedUsize, ClampedUsizeInclusive);
+
+sub_delta!(i8 => i16, ClampedI8, ClampedI8Inclusive);
+sub_delta!(i16 => i32, ClampedI16, ClampedI16Inclusive);
+sub_delta!(i32 => i64, ClampedI32, ClampedI32Inclusive);
+sub_delta!(i64 => i128, ClampedI64, ClampedI64Inclusive);
+sub_delta!(isize => i128, ClampedIsize, ClampedIsizeInclusive);
+
+macro_rules! add_wide {
+    ($inner:ty => $sum:ty, $clamped:ident, $clamped_inclusive:ident $(,)?) => {
+        /// Adding two values gives their sum as a raw integer, not another clamped value.
+        ///
+        /// The sum is widened, so it always fits, even for ranges near the limits of the inner type.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Add for $clamped<LOWER, UPPER> {
+            type Output = $sum;
+            fn add(self, rhs: Self) -> $sum {
+                self.0 as $sum + rhs.0 as $sum
+            }
+        }
+
+        /// Adding two values gives their sum as a raw integer, not another clamped value.
+        ///
+        /// The sum is widened, so it always fits, even for ranges near the limits of the inner type.
+        impl<const LOWER: $inner, const UPPER: $inner> ops::Add
+            for $clamped_inclusive<LOWER, UPPER>
+        {
+            type Output = $sum;
+            fn add(self, rhs: Self) -> $sum {
+                self.0 as $sum + rhs.0 as $sum
+            }
+        }
+    };
+}
+
+// There's no wider type for the sums of 128-bit integers.
+add_wide!(u8 => u16, ClampedU8, ClampedU8Inclusive);
+add_wide!(u16 => u32, ClampedU16, ClampedU16Inclusive);
+add_wide!(u32 => u64, ClampedU32, ClampedU32Inclusive);
+add_wide!(u64 => u128, ClampedU64, ClampedU64Inclusive);
+add_wide!(usize => u128, ClampedUsize, ClampedUsizeInclusive);
+
+add_wide!(i8 => i16, ClampedI8, ClampedI8Inclusive);
+add_wide!(i16 => i32, ClampedI16, ClampedI16Inclusive);
+add_wide!(i32 => i64, ClampedI32, ClampedI32Inclusive);
+add_wide!(i64 => i128, ClampedI64, ClampedI64Inclusive);
+add_wide!(isize => i128, ClampedIsize, ClampedIsizeInclusive);
+
+macro_rules! sum_wide {
+    (
+        $inner:ty => $sum:ty,
+        $clamped:ident,
+        $clamped_from:ident,
+        $clamped_inclusive:ident,
+        $clamped_to:ident,
+        $clamped_to_inclusive:ident $(,)?
+    ) => {
+        /// Sums are accumulated in a wider type, so won't overflow for realistic numbers of values.
+        impl<const LOWER: $inner, const UPPER: $inner> iter::Sum<$clamped<LOWER, UPPER>> for $sum {
+            fn sum<I: Iterator<Item = $clamped<LOWER, UPPER>>>(iter: I) -> $sum {
+                iter.map(|it| it.0 as $sum).sum()
+            }
+        }
+
+        impl<'a, const LOWER: $inner, const UPPER: $inner> iter::Sum<&'a $clamped<LOWER, UPPER>>
+            for $sum
+        {
+            fn sum<I: Iterator<Item = &'a $clamped<LOWER, UPPER>>>(iter: I) -> $sum {
+                iter.copied().sum()
+            }
+        }
+
+        /// Sums are accumulated in a wider type, so won't overflow for realistic numbers of values.
+        impl<const LOWER: $inner> iter::Sum<$clamped_from<LOWER>> for $sum {
+            fn sum<I: Iterator<Item = $clamped_from<LOWER>>>(iter: I) -> $sum {
+                iter.map(|it| it.0 as $sum).sum()
+            }
+        }
+
+        impl<'a, const LOWER: $inner> iter::Sum<&'a $clamped_from<LOWER>> for $sum {
+            fn sum<I: Iterator<Item = &'a $clamped_from<LOWER>>>(iter: I) -> $sum {
+                iter.copied().sum()
+            }
+        }
+
+        /// Sums are accumulated in a wider type, so won't overflow for realistic numbers of values.
+        impl<const LOWER: $inner, const UPPER: $inner> iter::Sum<$clamped_inclusive<LOWER, UPPER>>
+            for $sum
+        {
+            fn sum<I: Iterator<Item = $clamped_inclusive<LOWER, UPPER>>>(iter: I) -> $sum {
+                iter.map(|it| it.0 as $sum).sum()
+            }
+        }
+
+        impl<'a, const LOWER: $inner, const UPPER: $inner>
+            iter::Sum<&'a $clamped_inclusive<LOWER, UPPER>> for $sum
+        {
+            fn sum<I: Iterator<Item = &'a $clamped_inclusive<LOWER, UPPER>>>(iter: I) -> $sum {
+                iter.copied().sum()
+            }
+        }
+
+        /// Sums are accumulated in a wider type, so won't overflow for realistic numbers of values.
+        impl<const UPPER: $inner> iter::Sum<$clamped_to<UPPER>> for $sum {
+            fn sum<I: Iterator<Item = $clamped_to<UPPER>>>(iter: I) -> $sum {
+                iter.map(|it| it.0 as $sum).sum()
+            }
+        }
+
+        impl<'a, const UPPER: $inner> iter::Sum<&'a $clamped_to<UPPER>> for $sum {
+            fn sum<I: Iterator<Item = &'a $clamped_to<UPPER>>>(iter: I) -> $sum {
+                iter.copied().sum()
+            }
+        }
+
+        /// Sums are accumulated in a wider type, so won't overflow for realistic numbers of values.
+        impl<const UPPER: $inner> iter::Sum<$clamped_to_inclusive<UPPER>> for $sum {
+            fn sum<I: Iterator<Item = $clamped_to_inclusive<UPPER>>>(iter: I) -> $sum {
+                iter.map(|it| it.0 as $sum).sum()
+            }
+        }
+
+        impl<'a, const UPPER: $inner> iter::Sum<&'a $clamped_to_inclusive<UPPER>> for $sum {
+            fn sum<I: Iterator<Item = &'a $clamped_to_inclusive<UPPER>>>(iter: I) -> $sum {
+                iter.copied().sum()
+            }
+        }
+    };
+}
+
+// The accumulator for each inner type. There's no wider type for 128-bit integers.
+sum_wide!(
+    u8 => u32,
+    ClampedU8,
+    ClampedU8From,
+    ClampedU8Inclusive,
+    ClampedU8To,
+    ClampedU8ToInclusive,
+);
+sum_wide!(
+    u16 => u64,
+    ClampedU16,
+    ClampedU16From,
+    ClampedU16Inclusive,
+    ClampedU16To,
+    ClampedU16ToInclusive,
+);
+sum_wide!(
+    u32 => u64,
+    ClampedU32,
+    ClampedU32From,
+    ClampedU32Inclusive,
+    ClampedU32To,
+    ClampedU32ToInclusive,
+);
+sum_wide!(
+    u64 => u128,
+    ClampedU64,
+    ClampedU64From,
+    ClampedU64Inclusive,
+    ClampedU64To,
+    ClampedU64ToInclusive,
+);
+sum_wide!(
+    usize => u128,
+    ClampedUsize,
+    ClampedUsizeFrom,
+    ClampedUsizeInclusive,
+    ClampedUsizeTo,
+    ClampedUsizeToInclusive,
+);
+
+sum_wide!(
+    i8 => i32,
+    ClampedI8,
+    ClampedI8From,
+    ClampedI8Inclusive,
+    ClampedI8To,
+    ClampedI8ToInclusive,
+);
+sum_wide!(
+    i16 => i64,
+    ClampedI16,
+    ClampedI16From,
+    ClampedI16Inclusive,
+    ClampedI16To,
+    ClampedI16ToInclusive,
+);
+sum_wide!(
+    i32 => i64,
+    ClampedI32,
+    ClampedI32From,
+    ClampedI32Inclusive,
+    ClampedI32To,
+    ClampedI32ToInclusive,
+);
+sum_wide!(
+    i64 => i128,
+    ClampedI64,
+    ClampedI64From,
+    ClampedI64Inclusive,
+    ClampedI64To,
+    ClampedI64ToInclusive,
+);
+sum_wide!(
+    isize => i128,
+    ClampedIsize,
+    ClampedIsizeFrom,
+    ClampedIsizeInclusive,
+    ClampedIsizeTo,
+    ClampedIsizeToInclusive,
+);
+
+macro_rules! product_wide {
+    (
+        $inner:ty => $product:ty,
+        $clamped:ident,
+        $clamped_from:ident,
+        $clamped_inclusive:ident,
+        $clamped_to:ident,
+        $clamped_to_inclusive:ident $(,)?
+    ) => {
+        /// Products are accumulated in a wider type, but can still overflow, in which case use a checked fold.
+        impl<const LOWER: $inner, const UPPER: $inner> iter::Product<$clamped<LOWER, UPPER>>
+            for $product
+        {
+            fn product<I: Iterator<Item = $clamped<LOWER, UPPER>>>(iter: I) -> $product {
+                iter.map(|it| it.0 as $product).product()
+            }
+        }
+
+        impl<'a, const LOWER: $inner, const UPPER: $inner> iter::Product<&'a $clamped<LOWER, UPPER>>
+            for $product
+        {
+            fn product<I: Iterator<Item = &'a $clamped<LOWER, UPPER>>>(iter: I) -> $product {
+                iter.copied().product()
+            }
+        }
+
+        /// Products are accumulated in a wider type, but can still overflow, in which case use a checked fold.
+        impl<const LOWER: $inner> iter::Product<$clamped_from<LOWER>> for $product {
+            fn product<I: Iterator<Item = $clamped_from<LOWER>>>(iter: I) -> $product {
+                iter.map(|it| it.0 as $product).product()
+            }
+        }
+
+        impl<'a, const LOWER: $inner> iter::Product<&'a $clamped_from<LOWER>> for $product {
+            fn product<I: Iterator<Item = &'a $clamped_from<LOWER>>>(iter: I) -> $product {
+                iter.copied().product()
+            }
+        }
+
+        /// Products are accumulated in a wider type, but can still overflow, in which case use a checked fold.
+        impl<const LOWER: $inner, const UPPER: $inner>
+            iter::Product<$clamped_inclusive<LOWER, UPPER>> for $product
+        {
+            fn product<I: Iterator<Item = $clamped_inclusive<LOWER, UPPER>>>(iter: I) -> $product {
+                iter.map(|it| it.0 as $product).product()
+            }
+        }
+
+        impl<'a, const LOWER: $inner, const UPPER: $inner>
+            iter::Product<&'a $clamped_inclusive<LOWER, UPPER>> for $product
+        {
+            fn product<I: Iterator<Item = &'a $clamped_inclusive<LOWER, UPPER>>>(
+                iter: I,
+            ) -> $product {
+                iter.copied().product()
+            }
+        }
+
+        /// Products are accumulated in a wider type, but can still overflow, in which case use a checked fold.
+        impl<const UPPER: $inner> iter::Product<$clamped_to<UPPER>> for $product {
+            fn product<I: Iterator<Item = $clamped_to<UPPER>>>(iter: I) -> $product {
+                iter.map(|it| it.0 as $product).product()
+            }
+        }
+
+        impl<'a, const UPPER: $inner> iter::Product<&'a $clamped_to<UPPER>> for $product {
+            fn product<I: Iterator<Item = &'a $clamped_to<UPPER>>>(iter: I) -> $product {
+                iter.copied().product()
+            }
+        }
+
+        /// Products are accumulated in a wider type, but can still overflow, in which case use a checked fold.
+        impl<const UPPER: $inner> iter::Product<$clamped_to_inclusive<UPPER>> for $product {
+            fn product<I: Iterator<Item = $clamped_to_inclusive<UPPER>>>(iter: I) -> $product {
+                iter.map(|it| it.0 as $product).product()
+            }
+        }
+
+        impl<'a, const UPPER: $inner> iter::Product<&'a $clamped_to_inclusive<UPPER>> for $product {
+            fn product<I: Iterator<Item = &'a $clamped_to_inclusive<UPPER>>>(iter: I) -> $product {
+                iter.copied().product()
+            }
+        }
+    };
+}
+
+// The accumulator for each inner type. There's no wider type for 128-bit integers.
+product_wide!(
+    u8 => u64,
+    ClampedU8,
+    ClampedU8From,
+    ClampedU8Inclusive,
+    ClampedU8To,
+    ClampedU8ToInclusive,
+);
+product_wide!(
+    u16 => u64,
+    ClampedU16,
+    ClampedU16From,
+    ClampedU16Inclusive,
+    ClampedU16To,
+    ClampedU16ToInclusive,
+);
+product_wide!(
+    u32 => u128,
+    ClampedU32,
+    ClampedU32From,
+    ClampedU32Inclusive,
+    ClampedU32To,
+    ClampedU32ToInclusive,
+);
+product_wide!(
+    u64 => u128,
+    ClampedU64,
+    ClampedU64From,
+    ClampedU64Inclusive,
+    ClampedU64To,
+    ClampedU64ToInclusive,
+);
+product_wide!(
+    usize => u128,
+    ClampedUsize,
+    ClampedUsizeFrom,
+    ClampedUsizeInclusive,
+    ClampedUsizeTo,
+    ClampedUsizeToInclusive,
+);
+
+product_wide!(
+    i8 => i64,
+    ClampedI8,
+    ClampedI8From,
+    ClampedI8Inclusive,
+    ClampedI8To,
+    ClampedI8ToInclusive,
+);
+product_wide!(
+    i16 => i64,
+    ClampedI16,
+    ClampedI16From,
+    ClampedI16Inclusive,
+    ClampedI16To,
+    ClampedI16ToInclusive,
+);
+product_wide!(
+    i32 => i128,
+    ClampedI32,
+    ClampedI32From,
+    ClampedI32Inclusive,
+    ClampedI32To,
+    ClampedI32ToInclusive,
+);
+product_wide!(
+    i64 => i128,
+    ClampedI64,
+    ClampedI64From,
+    ClampedI64Inclusive,
+    ClampedI64To,
+    ClampedI64ToInclusive,
+);
+product_wide!(
+    isize => i128,
+    ClampedIsize,
+    ClampedIsizeFrom,
+    ClampedIsizeInclusive,
+    ClampedIsizeTo,
+    ClampedIsizeToInclusive,
+);
+
+macro_rules! narrow_pair {
+    (
+        $wide:ty,
+        $wide_clamped:ident,
+        $wide_clamped_from:ident,
+        $wide_clamped_inclusive:ident,
+        $wide_clamped_to:ident,
+        $wide_clamped_to_inclusive:ident;
+        $narrow:ty,
+        $narrow_clamped:ident,
+        $narrow_clamped_from:ident,
+        $narrow_clamped_inclusive:ident,
+        $narrow_clamped_to:ident,
+        $narrow_clamped_to_inclusive:ident $(;)?
+    ) => {
+        impl SaturatingFrom<$wide> for $narrow {
+            fn saturating_from(wide: $wide) -> $narrow {
+                wide.clamp(<$narrow>::MIN as $wide, <$narrow>::MAX as $wide) as $narrow
+            }
+        }
+
+        impl<const LOWER: $wide, const UPPER: $wide> SaturatingFrom<$wide_clamped<LOWER, UPPER>>
+            for $narrow
+        {
+            fn saturating_from(clamped: $wide_clamped<LOWER, UPPER>) -> $narrow {
+                <$narrow>::saturating_from(clamped.0)
+            }
+        }
+
+        impl<const L2: $narrow, const U2: $narrow> SaturatingFrom<$wide>
+            for $narrow_clamped<L2, U2>
+        {
+            fn saturating_from(wide: $wide) -> Self {
+                Self::saturating_from(<$narrow>::saturating_from(wide))
+            }
+        }
+
+        impl<const LOWER: $wide, const UPPER: $wide, const L2: $narrow, const U2: $narrow>
+            SaturatingFrom<$wide_clamped<LOWER, UPPER>> for $narrow_clamped<L2, U2>
+        {
+            fn saturating_from(clamped: $wide_clamped<LOWER, UPPER>) -> Self {
+                Self::saturating_from(<$narrow>::saturating_from(clamped.0))
+            }
+        }
+
+        impl<const LOWER: $wide> SaturatingFrom<$wide_clamped_from<LOWER>> for $narrow {
+            fn saturating_from(clamped: $wide_clamped_from<LOWER>) -> $narrow {
+                <$narrow>::saturating_from(clamped.0)
+            }
+        }
+
+        impl<const L2: $narrow> SaturatingFrom<$wide> for $narrow_clamped_from<L2> {
+            fn saturating_from(wide: $wide) -> Self {
+                Self::saturating_from(<$narrow>::saturating_from(wide))
+            }
+        }
+
+        impl<const LOWER: $wide, const L2: $narrow> SaturatingFrom<$wide_clamped_from<LOWER>>
+            for $narrow_clamped_from<L2>
+        {
+            fn saturating_from(clamped: $wide_clamped_from<LOWER>) -> Self {
+                Self::saturating_from(<$narrow>::saturating_from(clamped.0))
+            }
+        }
+
+        impl<const LOWER: $wide, const UPPER: $wide>
+            SaturatingFrom<$wide_clamped_inclusive<LOWER, UPPER>> for $narrow
+        {
+            fn saturating_from(clamped: $wide_clamped_inclusive<LOWER, UPPER>) -> $narrow {
+                <$narrow>::saturating_from(clamped.0)
+            }
+        }
+
+        impl<const L2: $narrow, const U2: $narrow> SaturatingFrom<$wide>
+            for $narrow_clamped_inclusive<L2, U2>
+        {
+            fn saturating_from(wide: $wide) -> Self {
+                Self::saturating_from(<$narrow>::saturating_from(wide))
+            }
+        }
+
+        impl<const LOWER: $wide, const UPPER: $wide, const L2: $narrow, const U2: $narrow>
+            SaturatingFrom<$wide_clamped_inclusive<LOWER, UPPER>>
+            for $narrow_clamped_inclusive<L2, U2>
+        {
+            fn saturating_from(clamped: $wide_clamped_inclusive<LOWER, UPPER>) -> Self {
+                Self::saturating_from(<$narrow>::saturating_from(clamped.0))
+            }
+        }
+
+        impl<const UPPER: $wide> SaturatingFrom<$wide_clamped_to<UPPER>> for $narrow {
+            fn saturating_from(clamped: $wide_clamped_to<UPPER>) -> $narrow {
+                <$narrow>::saturating_from(clamped.0)
+            }
+        }
+
+        impl<const U2: $narrow> SaturatingFrom<$wide> for $narrow_clamped_to<U2> {
+            fn saturating_from(wide: $wide) -> Self {
+                Self::saturating_from(<$narrow>::saturating_from(wide))
+            }
+        }
+
+        impl<const UPPER: $wide, const U2: $narrow> SaturatingFrom<$wide_clamped_to<UPPER>>
+            for $narrow_clamped_to<U2>
+        {
+            fn saturating_from(clamped: $wide_clamped_to<UPPER>) -> Self {
+                Self::saturating_from(<$narrow>::saturating_from(clamped.0))
+            }
+        }
+
+        impl<const UPPER: $wide> SaturatingFrom<$wide_clamped_to_inclusive<UPPER>> for $narrow {
+            fn saturating_from(clamped: $wide_clamped_to_inclusive<UPPER>) -> $narrow {
+                <$narrow>::saturating_from(clamped.0)
+            }
+        }
+
+        impl<const U2: $narrow> SaturatingFrom<$wide> for $narrow_clamped_to_inclusive<U2> {
+            fn saturating_from(wide: $wide) -> Self {
+                Self::saturating_from(<$narrow>::saturating_from(wide))
+            }
+        }
+
+        impl<const UPPER: $wide, const U2: $narrow>
+            SaturatingFrom<$wide_clamped_to_inclusive<UPPER>> for $narrow_clamped_to_inclusive<U2>
+        {
+            fn saturating_from(clamped: $wide_clamped_to_inclusive<UPPER>) -> Self {
+                Self::saturating_from(<$narrow>::saturating_from(clamped.0))
+            }
+        }
+        /// Runtime-checked, even for ranges that always fit.
+        impl<const LOWER: $wide, const UPPER: $wide> TryFrom<$wide_clamped<LOWER, UPPER>>
+            for $narrow
+        {
+            type Error = num::TryFromIntError;
+            fn try_from(clamped: $wide_clamped<LOWER, UPPER>) -> Result<$narrow, Self::Error> {
+                <$narrow>::try_from(clamped.0)
+            }
+        }
+
+        /// Runtime-checked, even for ranges that always fit.
+        impl<const LOWER: $wide> TryFrom<$wide_clamped_from<LOWER>> for $narrow {
+            type Error = num::TryFromIntError;
+            fn try_from(clamped: $wide_clamped_from<LOWER>) -> Result<$narrow, Self::Error> {
+                <$narrow>::try_from(clamped.0)
+            }
+        }
+
+        /// Runtime-checked, even for ranges that always fit.
+        impl<const LOWER: $wide, const UPPER: $wide> TryFrom<$wide_clamped_inclusive<LOWER, UPPER>>
+            for $narrow
+        {
+            type Error = num::TryFromIntError;
+            fn try_from(
+                clamped: $wide_clamped_inclusive<LOWER, UPPER>,
+            ) -> Result<$narrow, Self::Error> {
+                <$narrow>::try_from(clamped.0)
+            }
+        }
+
+        /// Runtime-checked, even for ranges that always fit.
+        impl<const UPPER: $wide> TryFrom<$wide_clamped_to<UPPER>> for $narrow {
+            type Error = num::TryFromIntError;
+            fn try_from(clamped: $wide_clamped_to<UPPER>) -> Result<$narrow, Self::Error> {
+                <$narrow>::try_from(clamped.0)
+            }
+        }
+
+        /// Runtime-checked, even for ranges that always fit.
+        impl<const UPPER: $wide> TryFrom<$wide_clamped_to_inclusive<UPPER>> for $narrow {
+            type Error = num::TryFromIntError;
+            fn try_from(
+                clamped: $wide_clamped_to_inclusive<UPPER>,
+            ) -> Result<$narrow, Self::Error> {
+                <$narrow>::try_from(clamped.0)
+            }
+        }
+
+        impl<const UPPER: $narrow> $narrow_clamped_to<UPPER> {
+            /// Widen the inner integer, and re-validate against `..U2`.
+            pub fn rebound_to<const U2: $wide>(
+                self,
+            ) -> Result<$wide_clamped_to<U2>, OutOfBoundsTo<$wide>> {
+                $wide_clamped_to::try_from(<$wide>::from(self.0))
+            }
+        }
+
+        impl<const UPPER: $narrow> $narrow_clamped_to_inclusive<UPPER> {
+            /// Widen the inner integer, and re-validate against `..=U2`.
+            pub fn rebound_to<const U2: $wide>(
+                self,
+            ) -> Result<$wide_clamped_to_inclusive<U2>, OutOfBoundsToInclusive<$wide>> {
+                $wide_clamped_to_inclusive::try_from(<$wide>::from(self.0))
+            }
+        }
+    };
+}
+
+narrow_pair!(
+    u16,
+    ClampedU16,
+    ClampedU16From,
+    ClampedU16Inclusive,
+    ClampedU16To,
+    ClampedU16ToInclusive;
+    u8,
+    ClampedU8,
+    ClampedU8From,
+    ClampedU8Inclusive,
+    ClampedU8To,
+    ClampedU8ToInclusive;
+);
+narrow_pair!(
+    u32,
+    ClampedU32,
+    ClampedU32From,
+    ClampedU32Inclusive,
+    ClampedU32To,
+    ClampedU32ToInclusive;
+    u16,
+    ClampedU16,
+    ClampedU16From,
+    ClampedU16Inclusive,
+    ClampedU16To,
+    ClampedU16ToInclusive;
+);
+narrow_pair!(
+    u64,
+    ClampedU64,
+    ClampedU64From,
+    ClampedU64Inclusive,
+    ClampedU64To,
+    ClampedU64ToInclusive;
+    u32,
+    ClampedU32,
+    ClampedU32From,
+    ClampedU32Inclusive,
+    ClampedU32To,
+    ClampedU32ToInclusive;
+);
+narrow_pair!(
+    u128,
+    ClampedU128,
     ClampedU128From,
     ClampedU128Inclusive,
     ClampedU128To,
-    ClampedU128ToInclusive,
+    ClampedU128ToInclusive;
+    u64,
+    ClampedU64,
+    ClampedU64From,
+    ClampedU64Inclusive,
+    ClampedU64To,
+    ClampedU64ToInclusive;
 );
-clamped!(
-    usize,
-    ClampedUsize,
-    ClampedUsizeFrom,
-    ClampedUsizeInclusive,
-    ClampedUsizeTo,
-    ClampedUsizeToInclusive,
+
+narrow_pair!(
+    i16,
+    ClampedI16,
+    ClampedI16From,
+    ClampedI16Inclusive,
+    ClampedI16To,
+    ClampedI16ToInclusive;
+    i8,
+    ClampedI8,
+    ClampedI8From,
+    ClampedI8Inclusive,
+    ClampedI8To,
+    ClampedI8ToInclusive;
+);
+narrow_pair!(
+    i32,
+    ClampedI32,
+    ClampedI32From,
+    ClampedI32Inclusive,
+    ClampedI32To,
+    ClampedI32ToInclusive;
+    i16,
+    ClampedI16,
+    ClampedI16From,
+    ClampedI16Inclusive,
+    ClampedI16To,
+    ClampedI16ToInclusive;
+);
+narrow_pair!(
+    i64,
+    ClampedI64,
+    ClampedI64From,
+    ClampedI64Inclusive,
+    ClampedI64To,
+    ClampedI64ToInclusive;
+    i32,
+    ClampedI32,
+    ClampedI32From,
+    ClampedI32Inclusive,
+    ClampedI32To,
+    ClampedI32ToInclusive;
+);
+narrow_pair!(
+    i128,
+    ClampedI128,
+    ClampedI128From,
+    ClampedI128Inclusive,
+    ClampedI128To,
+    ClampedI128ToInclusive;
+    i64,
+    ClampedI64,
+    ClampedI64From,
+    ClampedI64Inclusive,
+    ClampedI64To,
+    ClampedI64ToInclusive;
+);
+
+macro_rules! ascii {
+    ($($clamped:ident<$($param:ident),*>),* $(,)?) => {
+        $(
+            impl<$(const $param: u8),*> $clamped<$($param),*> {
+                /// The value as a [`char`].
+                ///
+                /// Only provided on the `u8` types, where every value is a valid `char`.
+                /// The result is ASCII when the range is within `0..0x80`.
+                pub fn as_char(self) -> char {
+                    self.0 as char
+                }
+
+                /// Construct from an ASCII [`char`].
+                pub fn from_ascii(c: char) -> Result<Self, FromAsciiError<<Self as TryFrom<u8>>::Error>> {
+                    if !c.is_ascii() {
+                        return Err(FromAsciiError::NotAscii(c));
+                    }
+                    Self::try_from(c as u8).map_err(FromAsciiError::OutOfBounds)
+                }
+            }
+
+            /// Construct from a Latin-1 [`char`], i.e. one in `'\0'..='\u{ff}'`.
+            impl<$(const $param: u8),*> TryFrom<char> for $clamped<$($param),*> {
+                type Error = FromCharError<<Self as TryFrom<u8>>::Error>;
+                fn try_from(c: char) -> Result<Self, Self::Error> {
+                    let byte = u8::try_from(c).map_err(|_| FromCharError::NotLatin1(c))?;
+                    Self::try_from(byte).map_err(FromCharError::OutOfBounds)
+                }
+            }
+        )*
+    };
+}
+
+ascii!(
+    ClampedU8<LOWER, UPPER>,
+    ClampedU8From<LOWER>,
+    ClampedU8Inclusive<LOWER, UPPER>,
+    ClampedU8To<UPPER>,
+    ClampedU8ToInclusive<UPPER>,
 );
 
-clamped!(
-    i8,
-    ClampedI8,
-    ClampedI8From,
-    ClampedI8Inclusive,
-    ClampedI8To,
-    ClampedI8ToInclusive,
-);
-clamped!(
-    i16,
-    ClampedI16,
-    ClampedI16From,
-    ClampedI16Inclusive,
-    ClampedI16To,
-    ClampedI16ToInclusive,
-);
-clamped!(
-    i32,
-    ClampedI32,
-    ClampedI32From,
-    ClampedI32Inclusive,
-    ClampedI32To,
-    ClampedI32ToInclusive,
-);
-clamped!(
-    i64,
-    ClampedI64,
-    ClampedI64From,
-    ClampedI64Inclusive,
-    ClampedI64To,
-    ClampedI64ToInclusive,
-);
-clamped!(
-    i128,
-    ClampedI128,
-    ClampedI128From,
-    ClampedI128Inclusive,
-    ClampedI128To,
-    ClampedI128ToInclusive,
-);
-clamped!(
-    isize,
-    ClampedIsize,
-    ClampedIsizeFrom,
-    ClampedIsizeInclusive,
-    ClampedIsizeTo,
-    ClampedIsizeToInclusive,
-);
+/// A single bit, `0..=1`.
+pub type ClampedU8Bit = ClampedU8Inclusive<0, 1>;
+
+impl ClampedU8Bit {
+    /// `false` is `0`, and `true` is `1`.
+    pub const fn from_bool(b: bool) -> Self {
+        Self(b as u8)
+    }
+
+    /// `0` is `false`, and `1` is `true`.
+    pub const fn to_bool(self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl From<bool> for ClampedU8Bit {
+    fn from(b: bool) -> Self {
+        Self::from_bool(b)
+    }
+}
+
+/// A percentage, `0..=100`.
+pub type Percentage = ClampedU8Inclusive<0, 100>;
+
+impl Percentage {
+    /// This percentage of `whole`, rounded to the nearest integer, with halves rounding up.
+    pub const fn of(self, whole: u64) -> u64 {
+        // the result is at most `whole`, so the cast can't truncate
+        ((whole as u128 * self.0 as u128 + 50) / 100) as u64
+    }
+
+    /// The percentage `numerator` is of `denominator`, rounded as in [`Self::of`],
+    /// and pinned to `100`.
+    ///
+    /// # Panics
+    /// If `denominator` is zero.
+    pub const fn from_ratio(numerator: u64, denominator: u64) -> Self {
+        let (numerator, denominator) = (numerator as u128, denominator as u128);
+        let percent = (numerator * 200 + denominator) / (denominator * 2);
+        match percent > 100 {
+            true => Self(100),
+            false => Self(percent as u8),
+        }
+    }
+}
+
+/// Proportionally map a value from one range onto another, e.g. to quantize ADC readings.
+///
+/// The lowest and highest values of the ranges map onto each other,
+/// and everything in between is rounded to the nearest value, with halves rounding up.
+/// The result is always in bounds.
+/// Fails to compile if either range is empty.
+pub fn requantize<const L1: u16, const U1: u16, const L2: u8, const U2: u8>(
+    value: ClampedU16<L1, U1>,
+) -> ClampedU8<L2, U2> {
+    const { assert!(L1 < U1 && L2 < U2) };
+    let from_max = u32::from(U1 - L1 - 1);
+    let to_max = u32::from(U2 - L2 - 1);
+    let offset = match from_max {
+        0 => 0,
+        _ => (u32::from(value.offset()) * to_max * 2 + from_max) / (from_max * 2),
+    };
+    ClampedU8(L2 + offset as u8)
+}
+
+/// A value for every value of a [`Bounded`] type, indexed by its offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LookupTable<C, V, const N: usize> {
+    values: [V; N],
+    key: PhantomData<C>,
+}
+
+impl<C: Bounded, V, const N: usize> LookupTable<C, V, N> {
+    /// Call `f` on every value of `C` to fill the table.
+    ///
+    /// # Panics
+    /// If `N` is not the number of values of `C`.
+    pub fn new(f: impl Fn(C) -> V) -> Self {
+        assert!(
+            N.checked_sub(1)
+                .is_none_or(|max| C::from_offset(max as u128).is_some())
+                && C::from_offset(N as u128).is_none(),
+            "N must be the number of values in the range"
+        );
+        Self {
+            values: std::array::from_fn(|offset| {
+                f(C::from_offset(offset as u128).expect("offset is in range"))
+            }),
+            key: PhantomData,
+        }
+    }
+
+    /// The values, in order of their keys.
+    pub fn as_slice(&self) -> &[V] {
+        &self.values
+    }
+}
+
+/// Every key has a value, so this never panics.
+impl<C: Bounded, V, const N: usize> ops::Index<C> for LookupTable<C, V, N> {
+    type Output = V;
+    fn index(&self, key: C) -> &V {
+        // offsets are in 0..N, checked in LookupTable::new
+        &self.values[key.to_offset() as usize]
+    }
+}
+
+impl<C: Bounded, V, const N: usize> ops::IndexMut<C> for LookupTable<C, V, N> {
+    fn index_mut(&mut self, key: C) -> &mut V {
+        &mut self.values[key.to_offset() as usize]
+    }
+}
+
+/// Ring buffer cursors.
+impl<const N: usize> ClampedUsize<0, N> {
+    /// Move by `delta`, wrapping around the range.
+    pub fn wrapping_offset(self, delta: isize) -> Self {
+        let distance = delta.unsigned_abs() % N;
+        // each branch stays below N, so cannot overflow
+        Self(match delta < 0 {
+            false if distance < N - self.0 => self.0 + distance,
+            false => distance - (N - self.0),
+            true if distance <= self.0 => self.0 - distance,
+            true => N - (distance - self.0),
+        })
+    }
+
+    /// Move by `delta`, returning [`None`] if the result is out of bounds.
+    pub fn checked_offset(self, delta: isize) -> Option<Self> {
+        Self::new(self.0.checked_add_signed(delta)?).ok()
+    }
+}
+
+/// Pin each of `inners` into the range, the lenient counterpart to collecting into a [`Result`].
+pub fn collect_saturating<T, C: SaturatingFrom<T>>(inners: impl IntoIterator<Item = T>) -> Vec<C> {
+    inners.into_iter().map(C::saturating_from).collect()
+}
+
+/// Write each of `values` separated by `sep`, into a single buffer.
+pub fn join_display<T: fmt::Display>(values: &[T], sep: &str) -> String {
+    use fmt::Write as _;
+    let mut joined = String::with_capacity(values.len() * (sep.len() + 1));
+    for (ix, value) in values.iter().enumerate() {
+        if ix != 0 {
+            joined.push_str(sep);
+        }
+        // writing to a String can't fail
+        let _ = write!(joined, "{value}");
+    }
+    joined
+}
+
+/// Pack two values into the bits of a [`u16`], by their [offsets](Bounded::to_offset).
+///
+/// `a` is in the low bits, and `b` is in the [`Bounded::NEEDED_BITS`] above them.
+/// Fails to compile if they don't fit.
+pub fn pack_two<A: Bounded, B: Bounded>(a: A, b: B) -> u16 {
+    const { assert!(A::NEEDED_BITS + B::NEEDED_BITS <= u16::BITS) };
+    // each offset fits in its bits, so the casts can't truncate
+    (a.to_offset() | b.to_offset() << A::NEEDED_BITS) as u16
+}
+
+/// The inverse of [`pack_two`], returning [`None`] if either value is out of bounds.
+pub fn unpack_two<A: Bounded, B: Bounded>(packed: u16) -> Option<(A, B)> {
+    const { assert!(A::NEEDED_BITS + B::NEEDED_BITS <= u16::BITS) };
+    let packed = u32::from(packed);
+    let mask = (1 << A::NEEDED_BITS) - 1;
+    let a = A::from_offset(u128::from(packed & mask))?;
+    let b = B::from_offset(u128::from(packed >> A::NEEDED_BITS))?;
+    Some((a, b))
+}
+
+/// The number of `samples` less than or equal to `value`.
+pub fn rank<const L: u8, const U: u8>(
+    value: ClampedU8<L, U>,
+    samples: &[ClampedU8<L, U>],
+) -> usize {
+    samples.iter().filter(|it| **it <= value).count()
+}
+
+/// The `p`th percentile of `samples`, by the nearest-rank method.
+///
+/// Returns [`None`] if there are no samples, or `p` is not in `0.0..=100.0`.
+pub fn percentile<const L: u8, const U: u8>(
+    p: f64,
+    samples: &[ClampedU8<L, U>],
+) -> Option<ClampedU8<L, U>> {
+    if samples.is_empty() || !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+    // the smallest rank covering p percent of the samples, counting from 1
+    let rank = ((p / 100.0 * samples.len() as f64).ceil() as usize).clamp(1, samples.len());
+    let mut samples = samples.to_vec();
+    let (_, nth, _) = samples.select_nth_unstable(rank - 1);
+    Some(*nth)
+}
+
+/// How to keep results in bounds in [`fold_clamped`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowMode {
+    /// Pin the result to the nearest bound.
+    Saturating,
+    /// Wrap around the range.
+    Wrapping,
+}
+
+/// Apply each of `deltas` in turn to `init`, keeping the running value in bounds according to `mode`.
+///
+/// # Panics
+/// If the range is empty.
+pub fn fold_clamped<const L: u8, const U: u8>(
+    init: ClampedU8<L, U>,
+    deltas: impl IntoIterator<Item = i16>,
+    mode: OverflowMode,
+) -> ClampedU8<L, U> {
+    assert!(L < U, "the range is empty");
+    deltas.into_iter().fold(init, |acc, delta| {
+        // can't overflow an i32
+        let next = i32::from(acc.0) + i32::from(delta);
+        match mode {
+            OverflowMode::Saturating => ClampedU8(next.clamp(i32::from(L), i32::from(U) - 1) as u8),
+            OverflowMode::Wrapping => {
+                let offset = (next - i32::from(L)).rem_euclid(i32::from(U - L));
+                ClampedU8(L + offset as u8)
+            }
+        }
+    })
+}
+
+/// An array which can be indexed by a [`ClampedUsize`] without bounds checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedArray<T, const N: usize>([T; N]);
+
+impl<T, const N: usize> BoundedArray<T, N> {
+    /// Wrap an array.
+    pub const fn new(array: [T; N]) -> Self {
+        Self(array)
+    }
+
+    /// The elements, as a slice.
+    pub const fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Unwrap the array.
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for BoundedArray<T, N> {
+    fn from(array: [T; N]) -> Self {
+        Self(array)
+    }
+}
+
+/// Fails to compile if `N` is zero, since there is no valid index.
+impl<T, const N: usize> ops::Index<ClampedUsize<0, N>> for BoundedArray<T, N> {
+    type Output = T;
+    fn index(&self, index: ClampedUsize<0, N>) -> &T {
+        const { assert!(N > 0) };
+        // SAFETY: index is in 0..N, and N is non-zero, so the range is valid
+        unsafe { self.0.get_unchecked(index.0) }
+    }
+}
+
+/// Fails to compile if `N` is zero, since there is no valid index.
+impl<T, const N: usize> ops::IndexMut<ClampedUsize<0, N>> for BoundedArray<T, N> {
+    fn index_mut(&mut self, index: ClampedUsize<0, N>) -> &mut T {
+        const { assert!(N > 0) };
+        // SAFETY: index is in 0..N, and N is non-zero, so the range is valid
+        unsafe { self.0.get_unchecked_mut(index.0) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test() {
+        type Clamped = ClampedU8<10, 20>;
+
+        Clamped::try_from(9).unwrap_err();
+        Clamped::try_from(10).unwrap();
+        Clamped::try_from(11).unwrap();
+
+        Clamped::try_from(19).unwrap();
+        Clamped::try_from(20).unwrap_err();
+        Clamped::try_from(21).unwrap_err();
+
+        type ClampedFrom = ClampedU8From<10>;
+
+        ClampedFrom::try_from(9).unwrap_err();
+        ClampedFrom::try_from(10).unwrap();
+        ClampedFrom::try_from(11).unwrap();
+
+        type ClampedInclusive = ClampedU8Inclusive<10, 20>;
+
+        ClampedInclusive::try_from(9).unwrap_err();
+        ClampedInclusive::try_from(10).unwrap();
+        ClampedInclusive::try_from(11).unwrap();
+
+        ClampedInclusive::try_from(19).unwrap();
+        ClampedInclusive::try_from(20).unwrap();
+        ClampedInclusive::try_from(21).unwrap_err();
+
+        type ClampedTo = ClampedU8To<10>;
+
+        ClampedTo::try_from(9).unwrap();
+        ClampedTo::try_from(10).unwrap_err();
+        ClampedTo::try_from(11).unwrap_err();
+
+        type ClampedToInclusive = ClampedU8ToInclusive<10>;
+
+        ClampedToInclusive::try_from(9).unwrap();
+        ClampedToInclusive::try_from(10).unwrap();
+        ClampedToInclusive::try_from(11).unwrap_err();
+    }
+
+    #[test]
+    fn ascii() {
+        type Printable = ClampedU8<0x20, 0x7F>;
+
+        assert_eq!(Printable::from_ascii('a').unwrap().as_char(), 'a');
+        assert!(matches!(
+            Printable::from_ascii('\n'),
+            Err(FromAsciiError::OutOfBounds(_))
+        ));
+        assert!(matches!(
+            Printable::from_ascii('é'),
+            Err(FromAsciiError::NotAscii('é'))
+        ));
+    }
+
+    #[test]
+    fn named() {
+        let err = ClampedU8::<0, 150>::try_new_named(200, "age").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "field `age`: The value 200 is not in the half-open range 0..150"
+        );
+    }
+
+    #[test]
+    fn const_compare() {
+        let a = ClampedI8::<-10, 10>::try_from(-5).unwrap();
+        let b = ClampedI8::<-10, 10>::try_from(5).unwrap();
+        assert!(a.const_eq(a));
+        assert!(!a.const_eq(b));
+        assert_eq!(a.const_cmp(b), cmp::Ordering::Less);
+        assert_eq!(b.const_cmp(a), cmp::Ordering::Greater);
+        assert_eq!(b.const_cmp(b), cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(ClampedU8::<10, 20>::new(15).unwrap(), 15);
+        ClampedU8::<10, 20>::new(20).unwrap_err();
+        assert_eq!(ClampedU8From::<10>::new(15).unwrap(), 15);
+        assert_eq!(ClampedU8Inclusive::<10, 20>::new(20).unwrap(), 20);
+        assert_eq!(ClampedU8To::<10>::new(5).unwrap(), 5);
+        assert_eq!(ClampedU8ToInclusive::<10>::new(10).unwrap(), 10);
+    }
+
+    #[test]
+    fn into_i128() {
+        fn sum(values: impl IntoIterator<Item = impl Into<i128>>) -> i128 {
+            values.into_iter().map(Into::into).sum()
+        }
+        let unsigned = [ClampedU64::<0, { u64::MAX }>::new(u64::MAX - 1).unwrap()];
+        let signed = [ClampedI8From::<{ i8::MIN }>::new(-1).unwrap()];
+        assert_eq!(sum(unsigned) + sum(signed), i128::from(u64::MAX) - 2);
+    }
+
+    #[test]
+    fn from_wrapping() {
+        assert_eq!(ClampedU8To::<10>::from_wrapping(25), 5);
+        assert_eq!(ClampedU8ToInclusive::<10>::from_wrapping(25), 3);
+        assert_eq!(ClampedU8ToInclusive::<{ u8::MAX }>::from_wrapping(25), 25);
+        // -128..-126
+        assert_eq!(ClampedI8To::<-126>::from_wrapping(0), -128);
+        assert_eq!(ClampedI8To::<-126>::from_wrapping(1), -127);
+        assert_eq!(ClampedI8ToInclusive::<-127>::from_wrapping(i8::MAX), -127);
+    }
+
+    #[test]
+    fn saturating_from() {
+        assert_eq!(ClampedU8::<10, 20>::saturating_from(0), 10);
+        assert_eq!(ClampedU8::<10, 20>::saturating_from(15), 15);
+        assert_eq!(ClampedU8::<10, 20>::saturating_from(20), 19);
+
+        assert_eq!(ClampedU8From::<10>::saturating_from(0), 10);
+        assert_eq!(ClampedU8From::<10>::saturating_from(255), 255);
+
+        assert_eq!(ClampedU8Inclusive::<10, 20>::saturating_from(0), 10);
+        assert_eq!(ClampedU8Inclusive::<10, 20>::saturating_from(21), 20);
+
+        assert_eq!(ClampedU8To::<10>::saturating_from(9), 9);
+        assert_eq!(ClampedU8To::<10>::saturating_from(10), 9);
+
+        assert_eq!(ClampedU8ToInclusive::<10>::saturating_from(10), 10);
+        assert_eq!(ClampedU8ToInclusive::<10>::saturating_from(11), 10);
+    }
+
+    #[test]
+    fn histogram() {
+        type Clamped = ClampedU8<10, 13>;
+        assert_eq!(Clamped::CARDINALITY, 3);
+        let values = [10, 12, 12, 10, 12].map(|it| Clamped::new(it).unwrap());
+        assert_eq!(Clamped::histogram(values), [2, 0, 3]);
+
+        type ClampedInclusive = ClampedI8Inclusive<-1, 1>;
+        assert_eq!(ClampedInclusive::CARDINALITY, 3);
+        let values = [-1, 1, 1].map(|it| ClampedInclusive::new(it).unwrap());
+        assert_eq!(ClampedInclusive::histogram(values), [1, 0, 2]);
+    }
+
+    #[test]
+    fn from_str() {
+        use std::error::Error as _;
+
+        assert_eq!("15".parse::<ClampedU8<10, 20>>().unwrap(), 15);
+
+        let err = "x".parse::<ClampedU8<10, 20>>().unwrap_err();
+        assert!(matches!(err, ParseClampedError::Parse(_)));
+        assert!(err.source().unwrap().is::<num::ParseIntError>());
+
+        let err = "25".parse::<ClampedU8<10, 20>>().unwrap_err();
+        assert!(matches!(err, ParseClampedError::OutOfBounds(_)));
+        assert!(err.source().unwrap().is::<OutOfBounds<u8>>());
+    }
+
+    #[test]
+    fn shrink_toward() {
+        let mut clamped = ClampedI8::<-100, 100>::new(90).unwrap();
+        let mut steps = vec![];
+        while let Some(next) = clamped.shrink_toward(-10) {
+            steps.push(next.0);
+            clamped = next;
+        }
+        assert_eq!(steps, (-10..90).rev().collect::<Vec<_>>());
+
+        let clamped = ClampedI8::<-100, 100>::new(-100).unwrap();
+        assert_eq!(clamped.shrink_toward(i8::MAX).unwrap(), -99);
+        assert_eq!(clamped.shrink_toward(i8::MIN), None);
+        let clamped = ClampedI8::<-100, 100>::new(99).unwrap();
+        assert_eq!(clamped.shrink_toward(i8::MAX), None);
+    }
+
+    #[test]
+    fn sorted() {
+        type Clamped = ClampedI8<-10, 10>;
+        let values = [3, -5, 0, 9, -5].map(|it| Clamped::new(it).unwrap());
+        assert_eq!(Clamped::sorted(values), [-5, -5, 0, 3, 9]);
+        assert!(values[1] < values[0]);
+    }
+
+    #[test]
+    fn add_signed() {
+        type Heading = ClampedI16Inclusive<-180, 180>;
+        let heading = Heading::new(170).unwrap();
+        assert_eq!(heading.saturating_add_signed(20), 180);
+        assert_eq!(heading.saturating_add_signed(i16::MIN), -180);
+        assert_eq!(heading.checked_add_signed(-20).unwrap(), 150);
+        assert_eq!(heading.checked_add_signed(20), None);
+        assert_eq!(heading.checked_add_signed(i16::MAX), None);
+    }
+
+    #[test]
+    fn bits() {
+        assert_eq!(ClampedU8::<0, 1>::NEEDED_BITS, 0);
+        assert_eq!(ClampedU8::<0, 2>::NEEDED_BITS, 1);
+        assert_eq!(ClampedU8::<10, 20>::NEEDED_BITS, 4);
+        assert_eq!(ClampedU16::<0, 16>::BITS, 16);
+        assert_eq!(ClampedU16::<0, 16>::NEEDED_BITS, 4);
+        assert_eq!(ClampedU16::<0, 17>::NEEDED_BITS, 5);
+        assert_eq!(
+            ClampedI8Inclusive::<{ i8::MIN }, { i8::MAX }>::NEEDED_BITS,
+            8
+        );
+        assert_eq!(ClampedI8Inclusive::<-1, 1>::NEEDED_BITS, 2);
+    }
+
+    #[test]
+    fn decode() {
+        type Clamped = ClampedU16<0, 1000>;
+        assert_eq!(
+            Clamped::try_from_le_slice(&[0xE8, 0x03, 0xFF])
+                .unwrap_err()
+                .to_string(),
+            "The value 1000 is not in the half-open range 0..1000"
+        );
+        assert_eq!(
+            Clamped::try_from_le_slice(&[0xE7, 0x03, 0xFF]).unwrap(),
+            999
+        );
+        assert_eq!(Clamped::try_from_be_slice(&[0x03, 0xE7]).unwrap(), 999);
+        assert!(matches!(
+            Clamped::try_from_be_slice(&[0x03]),
+            Err(DecodeError::InsufficientBytes {
+                needed: 2,
+                given: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn checked_not() {
+        let nibble = ClampedU8::<0, 16>::new(0b0101).unwrap();
+        assert_eq!(nibble.checked_not(), None);
+        let byte = ClampedU8From::<0>::new(0b0101).unwrap();
+        assert_eq!(byte.checked_not().unwrap(), 0b1111_1010);
+    }
+
+    #[test]
+    fn try_from_slice_array() {
+        type Clamped = ClampedU8<0, 8>;
+        let record = Clamped::try_from_slice_array::<4>(&[0, 1, 7, 3]).unwrap();
+        assert_eq!(record, [0, 1, 7, 3]);
+        assert!(matches!(
+            Clamped::try_from_slice_array::<4>(&[0, 1, 8, 3]),
+            Err(ArrayConvertError::OutOfBounds { index: 2, .. })
+        ));
+        assert!(matches!(
+            Clamped::try_from_slice_array::<4>(&[0, 1, 7]),
+            Err(ArrayConvertError::Length {
+                expected: 4,
+                given: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn iter() {
+        let all = ClampedU8::<10, 13>::iter().collect::<Vec<_>>();
+        assert_eq!(all, [10, 11, 12]);
+        assert_eq!(
+            ClampedU8::<10, 13>::iter().rev().collect::<Vec<_>>(),
+            [12, 11, 10]
+        );
+        assert_eq!(ClampedU8::<10, 13>::iter().size_hint(), (3, Some(3)));
+        assert_eq!(ClampedU8From::<250>::iter().count(), 6);
+        assert_eq!(
+            ClampedI8Inclusive::<-1, 1>::iter().collect::<Vec<_>>(),
+            [-1, 0, 1]
+        );
+        assert_eq!(
+            ClampedI8To::<-126>::iter().collect::<Vec<_>>(),
+            [-128, -127]
+        );
+        assert_eq!(ClampedU8ToInclusive::<{ u8::MAX }>::iter().count(), 256);
+        assert_eq!(ClampedU8To::<0>::iter().next(), None);
+
+        let mut iter = ClampedU8::<10, 12>::iter();
+        assert_eq!(iter.next().unwrap(), 10);
+        assert_eq!(iter.next_back().unwrap(), 11);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn reinterpret() {
+        let unsigned = ClampedU8::<0, 128>::new(127).unwrap();
+        assert_eq!(
+            unsigned.try_as_signed::<0, 127>().unwrap_err().to_string(),
+            "The value 127 is not in the half-open range 0..127"
+        );
+        assert_eq!(unsigned.try_as_signed::<0, 10>().ok(), None);
+        assert_eq!(
+            unsigned.try_as_signed::<{ i8::MIN }, { i8::MAX }>().ok(),
+            None
+        );
+        let unsigned = ClampedU8::<0, 128>::new(5).unwrap();
+        assert_eq!(unsigned.try_as_signed::<0, 10>().unwrap(), 5);
+
+        let unsigned = ClampedU8Inclusive::<0, 255>::new(0x80).unwrap();
+        assert_eq!(unsigned.try_as_signed::<{ i8::MIN }, 0>().unwrap(), -128);
+
+        let signed = ClampedI8From::<{ i8::MIN }>::new(-128).unwrap();
+        assert_eq!(signed.try_as_unsigned::<0x80>().unwrap(), 0x80);
+    }
+
+    #[test]
+    fn iterator_ext() {
+        let readings = [0u8, 50, 200];
+        let clamped = readings
+            .into_iter()
+            .clamped_saturating::<ClampedU8<10, 100>>()
+            .collect::<Vec<_>>();
+        assert_eq!(clamped, [10, 50, 99]);
+
+        let mut clamped = readings.into_iter().clamped_try::<ClampedU8<10, 100>>();
+        clamped.next().unwrap().unwrap_err();
+        assert_eq!(clamped.next().unwrap().unwrap(), 50);
+        clamped.next().unwrap().unwrap_err();
+    }
+
+    #[test]
+    fn power_of_two() {
+        type Capacity = ClampedUsizeInclusive<1, 65536>;
+        assert!(Capacity::new(1024).unwrap().is_power_of_two());
+        assert!(!Capacity::new(1000).unwrap().is_power_of_two());
+        let capacity = Capacity::new(1000).unwrap();
+        assert_eq!(capacity.checked_next_power_of_two().unwrap(), 1024);
+        let capacity = Capacity::new(65535).unwrap();
+        assert_eq!(capacity.checked_next_power_of_two().unwrap(), 65536);
+        let capacity = ClampedUsizeInclusive::<1, 65535>::new(65535).unwrap();
+        assert_eq!(capacity.checked_next_power_of_two(), None);
+    }
+
+    #[test]
+    fn requantize() {
+        let adc = |it| ClampedU16::<0, 4096>::new(it).unwrap();
+        assert_eq!(super::requantize::<0, 4096, 0, { u8::MAX }>(adc(0)), 0);
+        assert_eq!(super::requantize::<0, 4096, 0, { u8::MAX }>(adc(4095)), 254);
+        assert_eq!(super::requantize::<0, 4096, 0, { u8::MAX }>(adc(2048)), 127);
+        assert_eq!(super::requantize::<0, 4096, 10, 13>(adc(1023)), 10);
+        assert_eq!(super::requantize::<0, 4096, 10, 13>(adc(1024)), 11);
+        assert_eq!(super::requantize::<0, 4096, 10, 13>(adc(4095)), 12);
+
+        let single = ClampedU16::<5, 6>::new(5).unwrap();
+        assert_eq!(super::requantize::<5, 6, 10, 20>(single), 10);
+    }
+
+    #[test]
+    fn hash_errors() {
+        use std::collections::HashSet;
+
+        let failures = [200, 150, 200]
+            .into_iter()
+            .filter_map(|it| ClampedU8::<0, 150>::new(it).err())
+            .collect::<HashSet<_>>();
+        assert_eq!(failures.len(), 2);
+        assert!(failures.contains(&ClampedU8::<0, 150>::new(150).unwrap_err()));
+    }
+
+    #[test]
+    fn bit() {
+        assert_eq!(ClampedU8Bit::from_bool(false), 0);
+        assert_eq!(ClampedU8Bit::from_bool(true), 1);
+        assert_eq!(ClampedU8Bit::from(true), ClampedU8Bit::from_bool(true));
+        assert!(!ClampedU8Bit::from(false).to_bool());
+        assert!(ClampedU8Bit::new(1).unwrap().to_bool());
+        assert!(!ClampedU8Bit::new(0).unwrap().to_bool());
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut clamped = ClampedU8::<10, 20>::new(10).unwrap();
+        clamped += 9;
+        assert_eq!(clamped, 19);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "out of bounds"]
+    fn add_assign_out_of_bounds() {
+        let mut clamped = ClampedU8::<10, 20>::new(10).unwrap();
+        clamped += 10;
+    }
+
+    #[test]
+    fn option_result_ext() {
+        assert_eq!(Some(5u8).clamp_opt::<ClampedU8<0, 10>>().unwrap(), 5);
+        assert_eq!(Some(50u8).clamp_opt::<ClampedU8<0, 10>>(), None);
+        assert_eq!(None::<u8>.clamp_opt::<ClampedU8<0, 10>>(), None);
+
+        assert_eq!(
+            "5".parse::<u8>().clamp_res::<ClampedU8<0, 10>>().unwrap(),
+            5
+        );
+        assert!(matches!(
+            "50".parse::<u8>().clamp_res::<ClampedU8<0, 10>>(),
+            Err(ClampOrError::OutOfBounds(_))
+        ));
+        assert!(matches!(
+            "x".parse::<u8>().clamp_res::<ClampedU8<0, 10>>(),
+            Err(ClampOrError::Upstream(_))
+        ));
+    }
+
+    #[test]
+    fn signum() {
+        let heading = |it| ClampedI16Inclusive::<-180, 180>::new(it).unwrap();
+        assert_eq!(heading(-90).signum(), -1);
+        assert_eq!(heading(0).signum(), 0);
+        assert_eq!(heading(180).signum(), 1);
+    }
+
+    #[test]
+    fn rem() {
+        let clamped = ClampedU8::<10, 20>::new(17).unwrap();
+        assert_eq!(clamped % 5, 2);
+    }
+
+    #[test]
+    fn min_max_of() {
+        type Clamped = ClampedI8<-10, 10>;
+        let values = [3, -5, 9].map(|it| Clamped::new(it).unwrap());
+        assert_eq!(Clamped::min_of(values).unwrap(), -5);
+        assert_eq!(Clamped::max_of(values).unwrap(), 9);
+        assert_eq!(Clamped::min_of([]), None);
+        assert_eq!(Clamped::max_of([]), None);
+    }
+
+    #[test]
+    fn increment_decrement() {
+        let clamped = ClampedU8::<0, 2>::new(0).unwrap();
+        assert_eq!(clamped.decrement(), None);
+        assert_eq!(clamped.increment().unwrap(), 1);
+        assert_eq!(clamped.increment().unwrap().increment(), None);
+
+        let clamped = ClampedU8Inclusive::<0, { u8::MAX }>::new(u8::MAX).unwrap();
+        assert_eq!(clamped.increment(), None);
+        assert_eq!(clamped.decrement().unwrap(), u8::MAX - 1);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        use serde::{de::IntoDeserializer as _, Deserialize as _};
+
+        let de = 5u8.into_deserializer();
+        let clamped: Result<_, serde::de::value::Error> = ClampedU8::<0, 10>::deserialize(de);
+        assert_eq!(clamped.unwrap(), 5);
+
+        let de = 20u8.into_deserializer();
+        let err: serde::de::value::Error = ClampedU8::<0, 10>::deserialize(de).unwrap_err();
+        assert!(err.to_string().contains("0..10"), "{err}");
+
+        let de = "5".into_deserializer();
+        let clamped: Result<_, serde::de::value::Error> = ClampedU8::<0, 10>::deserialize(de);
+        assert_eq!(clamped.unwrap(), 5);
+
+        for s in ["20", "300", "five"] {
+            let de = s.into_deserializer();
+            let res: Result<_, serde::de::value::Error> = ClampedU8::<0, 10>::deserialize(de);
+            assert!(res.is_err(), "{s}");
+        }
+
+        let de = (-3i64).into_deserializer();
+        let clamped: Result<_, serde::de::value::Error> = ClampedI8From::<-5>::deserialize(de);
+        assert_eq!(clamped.unwrap(), -3);
+
+        let de = 11u8.into_deserializer();
+        let err: serde::de::value::Error = ClampedU8ToInclusive::<10>::deserialize(de).unwrap_err();
+        assert!(err.to_string().contains("inclusive range ..=10"), "{err}");
+    }
+
+    #[test]
+    fn const_saturating_contains() {
+        const TABLE: [ClampedU8<10, 20>; 3] = [
+            ClampedU8::saturating_from(0),
+            ClampedU8::saturating_from(15),
+            ClampedU8::saturating_from(255),
+        ];
+        assert_eq!(TABLE, [10, 15, 19]);
+
+        const { assert!(ClampedU8::<10, 20>::contains(10)) };
+        assert!(!ClampedU8::<10, 20>::contains(20));
+        assert!(ClampedU8From::<10>::contains(255));
+        assert!(ClampedU8Inclusive::<10, 20>::contains(20));
+        assert!(!ClampedU8To::<10>::contains(10));
+        assert!(ClampedU8ToInclusive::<10>::contains(10));
+    }
+
+    #[test]
+    fn equivalent_ranges() {
+        let inclusive = ClampedU8Inclusive::<0, 100>::new(100).unwrap();
+        let to_inclusive = ClampedU8ToInclusive::<100>::from(inclusive);
+        assert_eq!(ClampedU8Inclusive::from(to_inclusive), inclusive);
+
+        let clamped = ClampedI8::<{ i8::MIN }, 0>::new(-1).unwrap();
+        let to = ClampedI8To::<0>::from(clamped);
+        assert_eq!(ClampedI8::from(to), clamped);
+    }
+
+    #[test]
+    fn wrapping() {
+        let clamped = ClampedU8::<10, 13>::new(12).unwrap();
+        assert_eq!(clamped.wrapping_increment(), 10);
+        assert_eq!(clamped.wrapping_add(4), 10);
+        assert_eq!(clamped.wrapping_add(u8::MAX), 12);
+        assert_eq!(clamped.wrapping_sub(5), 10);
+        assert_eq!(
+            ClampedU8::<10, 13>::new(10).unwrap().wrapping_decrement(),
+            12
+        );
+
+        let heading = ClampedI16Inclusive::<-180, 179>::new(170).unwrap();
+        assert_eq!(heading.wrapping_add(20), -170);
+        assert_eq!(heading.wrapping_add(-360), 170);
+        for rhs in [i16::MIN, -1000, -1, 0, 1, 1000, i16::MAX] {
+            let add = (170 + i32::from(rhs) + 180).rem_euclid(360) - 180;
+            let sub = (170 - i32::from(rhs) + 180).rem_euclid(360) - 180;
+            assert_eq!(i32::from(heading.wrapping_add(rhs).0), add);
+            assert_eq!(i32::from(heading.wrapping_sub(rhs).0), sub);
+        }
+
+        let byte = ClampedU8Inclusive::<0, { u8::MAX }>::new(u8::MAX).unwrap();
+        assert_eq!(byte.wrapping_increment(), 0);
+        assert_eq!(byte.wrapping_sub(u8::MAX), 0);
+
+        let clamped = ClampedI8::<-1, 1>::new(0).unwrap();
+        for rhs in i8::MIN..=i8::MAX {
+            let expected = (i16::from(rhs) + 1).rem_euclid(2) - 1;
+            assert_eq!(clamped.wrapping_add(rhs), expected as i8);
+        }
+    }
+
+    #[test]
+    fn narrow() {
+        assert_eq!(ClampedUsizeTo::<10>::try_from_u32(5).unwrap(), 5);
+        assert!(matches!(
+            ClampedUsizeTo::<10>::try_from_u64(10),
+            Err(NarrowError::OutOfBounds(_))
+        ));
+        // the literal still infers as usize
+        assert_eq!(ClampedUsize::<0, 10>::try_from(5).unwrap(), 5);
+        if usize::BITS < 64 {
+            assert!(matches!(
+                ClampedUsizeFrom::<0>::try_from_u64(u64::MAX),
+                Err(NarrowError::DoesNotFit(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn abs_diff_in() {
+        let a = ClampedU8::<0, 100>::new(10).unwrap();
+        let b = ClampedU8::<0, 100>::new(99).unwrap();
+        assert_eq!(a.abs_diff_in::<0, 100>(b).unwrap(), 89);
+        assert_eq!(b.abs_diff_in::<0, 100>(a).unwrap(), 89);
+        b.abs_diff_in::<0, 50>(a).unwrap_err();
+
+        let a = ClampedI8Inclusive::<{ i8::MIN }, { i8::MAX }>::new(i8::MIN).unwrap();
+        let b = ClampedI8Inclusive::<{ i8::MIN }, { i8::MAX }>::new(i8::MAX).unwrap();
+        assert_eq!(a.abs_diff_in::<0, { u8::MAX }>(b).ok(), None);
+        assert_eq!(a.abs_diff_in::<250, { u8::MAX }>(a).ok(), None);
+        assert_eq!(b.abs_diff_in::<0, 1>(b).unwrap(), 0);
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(ClampedI8::<-10, 10>::default(), 0);
+        assert_eq!(ClampedU8Inclusive::<0, 0>::default(), 0);
+    }
+
+    #[test]
+    fn iter_from_to() {
+        type Clamped = ClampedU8<10, 15>;
+        let cursor = Clamped::new(12).unwrap();
+        assert_eq!(Clamped::iter_from(cursor).collect::<Vec<_>>(), [12, 13, 14]);
+        assert_eq!(Clamped::iter_to(cursor).collect::<Vec<_>>(), [10, 11]);
+        assert_eq!(Clamped::iter_from(cursor).len(), 3);
+        assert_eq!(Clamped::iter_to(cursor).len(), 2);
+        assert_eq!(Clamped::iter_to(Clamped::new(10).unwrap()).len(), 0);
+        assert_eq!(
+            ClampedI16To::<{ i16::MAX }>::iter().len(),
+            usize::from(u16::MAX)
+        );
+        assert_eq!(
+            ClampedI16ToInclusive::<{ i16::MAX }>::iter().count(),
+            1 << 16
+        );
+    }
+
+    #[test]
+    fn sub_delta() {
+        let a = ClampedU8::<0, { u8::MAX }>::new(0).unwrap();
+        let b = ClampedU8::<0, { u8::MAX }>::new(200).unwrap();
+        assert_eq!(a - b, -200);
+        assert_eq!(b - a, 200);
+
+        let a = ClampedI8Inclusive::<{ i8::MIN }, { i8::MAX }>::new(i8::MIN).unwrap();
+        let b = ClampedI8Inclusive::<{ i8::MIN }, { i8::MAX }>::new(i8::MAX).unwrap();
+        assert_eq!(a - b, -255);
+    }
+
+    #[test]
+    fn validate_all() {
+        type Clamped = ClampedU8<0, 150>;
+        assert_eq!(Clamped::validate_all(&[1, 2]).unwrap(), [1, 2]);
+        let errs = Clamped::validate_all(&[200, 2, 150]).unwrap_err();
+        assert_eq!(
+            errs.into_iter().map(|(index, _)| index).collect::<Vec<_>>(),
+            [0, 2]
+        );
+    }
+
+    #[test]
+    fn range_bounds() {
+        use std::collections::BTreeMap;
+
+        fn drain(range: impl ops::RangeBounds<usize>) -> Vec<usize> {
+            vec![0, 1, 2, 3, 4, 5].drain(range).collect()
+        }
+        assert_eq!(drain(ClampedUsize::<1, 3>::new(1).unwrap()), [1, 2]);
+        assert_eq!(drain(ClampedUsizeFrom::<4>::new(4).unwrap()), [4, 5]);
+        assert_eq!(
+            drain(ClampedUsizeInclusive::<1, 3>::new(1).unwrap()),
+            [1, 2, 3]
+        );
+        assert_eq!(drain(ClampedUsizeTo::<2>::new(0).unwrap()), [0, 1]);
+        assert_eq!(
+            drain(ClampedUsizeToInclusive::<2>::new(0).unwrap()),
+            [0, 1, 2]
+        );
+
+        let map = BTreeMap::from([(1, 'a'), (5, 'b'), (9, 'c')]);
+        let clamped = ClampedU8::<2, 9>::new(2).unwrap();
+        assert_eq!(map.range(clamped).collect::<Vec<_>>(), [(&5, &'b')]);
+    }
+
+    #[test]
+    fn saturating_into() {
+        let telemetry = ClampedU16::<0, 1000>::new(999).unwrap();
+        let narrow: ClampedU8<0, 100> = telemetry.saturating_into();
+        assert_eq!(narrow, 99);
+        let narrow: u8 = telemetry.saturating_into();
+        assert_eq!(narrow, u8::MAX);
+
+        let narrow: ClampedI16Inclusive<-100, 100> = (-40_000i32).saturating_into();
+        assert_eq!(narrow, -100);
+        let narrow: i8 = 1000i16.saturating_into();
+        assert_eq!(narrow, i8::MAX);
+    }
+
+    #[test]
+    fn checked_shift() {
+        let scaled = ClampedU16::<1, 1024>::new(4).unwrap();
+        assert_eq!(scaled.checked_shl(4).unwrap(), 64);
+        assert_eq!(scaled.checked_shl(8), None);
+        assert_eq!(scaled.checked_shl(16), None);
+        assert_eq!(scaled.checked_shr(2).unwrap(), 1);
+        assert_eq!(scaled.checked_shr(3), None);
+        assert_eq!(
+            ClampedI8Inclusive::<-64, 64>::new(-8)
+                .unwrap()
+                .checked_shl(3)
+                .unwrap(),
+            -64
+        );
+    }
+
+    #[test]
+    fn gcd_lcm() {
+        let a = ClampedU8::<1, 100>::new(12).unwrap();
+        let b = ClampedU8::<1, 100>::new(18).unwrap();
+        assert_eq!(a.gcd(b), 6);
+        assert_eq!(a.checked_lcm(b), Some(36));
+
+        let a = ClampedU8From::<0>::new(0).unwrap();
+        let b = ClampedU8From::<0>::new(7).unwrap();
+        assert_eq!(a.gcd(b), 7);
+        assert_eq!(a.checked_lcm(b), Some(0));
+
+        let a = ClampedU8To::<255>::new(254).unwrap();
+        let b = ClampedU8To::<255>::new(253).unwrap();
+        assert_eq!(a.checked_lcm(b), None);
+    }
+
+    #[test]
+    fn neg() {
+        let centered = ClampedI8::<-100, 101>::new(-100).unwrap();
+        assert!(ClampedI8::<-100, 101>::is_symmetric());
+        assert_eq!(centered.neg_unchecked(), 100);
+        assert_eq!((-centered).unwrap(), 100);
+
+        assert!(ClampedI8Inclusive::<-100, 100>::is_symmetric());
+        assert!(!ClampedI8Inclusive::<-100, 99>::is_symmetric());
+        assert!(!ClampedI8Inclusive::<{ i8::MIN }, { i8::MAX }>::is_symmetric());
+        assert!(!ClampedI8::<{ i8::MIN }, { i8::MAX }>::is_symmetric());
+
+        let positive = ClampedI8From::<0>::new(5).unwrap();
+        assert!((-positive).is_err());
+        let negative = ClampedI8To::<0>::new(-5).unwrap();
+        assert!((-negative).is_err());
+        assert_eq!((-ClampedI8ToInclusive::<0>::new(0).unwrap()).unwrap(), 0);
+    }
+
+    #[test]
+    fn neg_min() {
+        let min = ClampedI8Inclusive::<{ i8::MIN }, { i8::MAX }>::new(i8::MIN).unwrap();
+        assert!((-min).is_err());
+        assert!((-ClampedI8::<{ i8::MIN }, 0>::new(i8::MIN).unwrap()).is_err());
+        assert!((-ClampedI8From::<{ i8::MIN }>::new(i8::MIN).unwrap()).is_err());
+        assert!((-ClampedI8To::<0>::new(i8::MIN).unwrap()).is_err());
+        assert!((-ClampedI8ToInclusive::<{ i8::MAX }>::new(i8::MIN).unwrap()).is_err());
+    }
+
+    #[test]
+    #[should_panic = "the range is not symmetric"]
+    fn neg_unchecked_asymmetric() {
+        ClampedI8Inclusive::<-10, 5>::new(0)
+            .unwrap()
+            .neg_unchecked();
+    }
+
+    #[test]
+    fn mul() {
+        let clamped = ClampedI8::<{ i8::MIN }, 100>::new(-50).unwrap();
+        assert_eq!((clamped * -1).unwrap(), 50);
+        assert_eq!((clamped * 2).unwrap(), -100);
+        // overflows the inner type, so must not saturate to the lower bound
+        assert_eq!(
+            (clamped * 3).unwrap_err(),
+            ArithmeticError::Overflow { given: -50 }
+        );
+        assert!(matches!(clamped * -2, Err(ArithmeticError::OutOfBounds(_))));
+    }
+
+    #[test]
+    fn cross_kind_cmp() {
+        let half_open = ClampedU8::<0, 10>::new(9).unwrap();
+        let inclusive = ClampedU8Inclusive::<0, 9>::new(9).unwrap();
+        assert_eq!(half_open, inclusive);
+        assert_eq!(inclusive, half_open);
+
+        let to = ClampedU8To::<5>::new(4).unwrap();
+        assert!(to < half_open);
+        assert!(inclusive > to);
+        assert!(ClampedU8From::<0>::new(4).unwrap() == to);
+        assert!(ClampedU8ToInclusive::<4>::new(4).unwrap() <= to);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
     #[test]
-    fn test() {
+    fn from_f64_saturating() {
+        assert_eq!(ClampedU8::<10, 20>::from_f64_saturating(12.5), 13);
+        assert_eq!(ClampedU8::<10, 20>::from_f64_saturating(12.4), 12);
+        assert_eq!(ClampedU8::<10, 20>::from_f64_saturating(-1e9), 10);
+        assert_eq!(ClampedU8::<10, 20>::from_f64_saturating(f64::INFINITY), 19);
+        assert_eq!(ClampedU8::<10, 20>::from_f64_saturating(f64::NAN), 10);
+        assert_eq!(ClampedI8To::<0>::from_f64_saturating(f64::NAN), i8::MIN);
+        assert_eq!(ClampedI8From::<0>::from_f64_saturating(-2.5), 0);
+    }
+
+    #[test]
+    fn into_iter() {
+        let single = ClampedU8::<0, 10>::new(3).unwrap();
+        assert_eq!(single.into_iter().collect::<Vec<_>>(), [3]);
+        let chained = single
+            .into_iter()
+            .chain(ClampedU8::<0, 10>::iter_from(ClampedU8::new(8).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(chained, [3, 8, 9]);
+    }
+
+    #[test]
+    fn clamp_const() {
+        const GAINS: [ClampedI8Inclusive<-12, 12>; 3] = [
+            ClampedI8Inclusive::clamp_const(-100),
+            ClampedI8Inclusive::clamp_const(3),
+            ClampedI8Inclusive::clamp_const(100),
+        ];
+        assert_eq!(GAINS, [-12, 3, 12]);
+        const LIMIT: ClampedU8To<10> = ClampedU8To::clamp_const(u8::MAX);
+        assert_eq!(LIMIT, 9);
+    }
+
+    #[test]
+    fn bounded_array() {
+        let mut array = BoundedArray::new([10, 20, 30]);
+        let index = ClampedUsize::<0, 3>::new(2).unwrap();
+        assert_eq!(array[index], 30);
+        array[index] += 1;
+        assert_eq!(array.as_slice(), [10, 20, 31]);
+        let total = ClampedUsize::<0, 3>::iter().map(|i| array[i]).sum::<i32>();
+        assert_eq!(total, 61);
+    }
+
+    #[test]
+    fn ring_offset() {
+        let cursor = ClampedUsize::<0, 5>::new(3).unwrap();
+        assert_eq!(cursor.wrapping_offset(1), 4);
+        assert_eq!(cursor.wrapping_offset(2), 0);
+        assert_eq!(cursor.wrapping_offset(-3), 0);
+        assert_eq!(cursor.wrapping_offset(-4), 4);
+        assert_eq!(cursor.wrapping_offset(12), 0);
+        // 2^63 % 5 == 3
+        assert_eq!(cursor.wrapping_offset(isize::MIN), 0);
+        assert_eq!(cursor.checked_offset(1).unwrap(), 4);
+        assert_eq!(cursor.checked_offset(2), None);
+        assert_eq!(cursor.checked_offset(-4), None);
+
+        let cursor = ClampedUsize::<0, { usize::MAX }>::new(usize::MAX - 1).unwrap();
+        assert_eq!(cursor.wrapping_offset(1), 0);
+        assert_eq!(cursor.wrapping_offset(isize::MAX), isize::MAX as usize - 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "UPPER > <i8>::MIN"]
+    fn try_from_empty_to() {
+        let _ = ClampedI8To::<{ i8::MIN }>::try_from(0);
+    }
+
+    #[test]
+    fn from_str_sign() {
+        assert_eq!("+5".parse::<ClampedU8<0, 10>>().unwrap(), 5);
+        assert_eq!("5".parse::<ClampedU8<0, 10>>().unwrap(), 5);
+        assert_eq!("+5".parse::<ClampedI8Inclusive<-10, 10>>().unwrap(), 5);
+        assert_eq!("-5".parse::<ClampedI8Inclusive<-10, 10>>().unwrap(), -5);
+        for invalid in ["++5", "+", "", " 5", "5 ", "+ 5", "-5", "+-5", "5+"] {
+            let err = invalid.parse::<ClampedU8<0, 10>>().unwrap_err();
+            assert!(matches!(err, ParseClampedError::Parse(_)), "{invalid:?}");
+        }
+        assert!("+-5".parse::<ClampedI8Inclusive<-10, 10>>().is_err());
+        assert!("+15".parse::<ClampedU8<0, 10>>().is_err());
+    }
+
+    #[test]
+    fn add_wide() {
+        let a = ClampedU8Inclusive::<0, 255>::new(200).unwrap();
+        let b = ClampedU8Inclusive::<0, 255>::new(100).unwrap();
+        let sum: u16 = a + b;
+        assert_eq!(sum, 300);
+        let a = ClampedI8::<-128, 0>::new(-100).unwrap();
+        assert_eq!(a + a, -200i16);
+    }
+
+    #[test]
+    fn percentage() {
+        let half = Percentage::new(50).unwrap();
+        assert_eq!(half.of(7), 4);
+        assert_eq!(half.of(u64::MAX), u64::MAX / 2 + 1);
+        assert_eq!(Percentage::new(100).unwrap().of(u64::MAX), u64::MAX);
+        assert_eq!(Percentage::new(33).unwrap().of(10), 3);
+
+        assert_eq!(Percentage::from_ratio(1, 3), 33);
+        assert_eq!(Percentage::from_ratio(2, 3), 67);
+        assert_eq!(Percentage::from_ratio(1, 200), 1);
+        assert_eq!(Percentage::from_ratio(5, 1), 100);
+        assert_eq!(Percentage::from_ratio(u64::MAX, u64::MAX), 100);
+    }
+
+    #[test]
+    #[cfg(feature = "nohash-hasher")]
+    fn nohash() {
+        let mut map = nohash_hasher::IntMap::default();
+        map.insert(ClampedU16::<0, 1000>::new(999).unwrap(), "last");
+        map.insert(ClampedU16::<0, 1000>::new(0).unwrap(), "first");
+        assert_eq!(map[&ClampedU16::new(999).unwrap()], "last");
+    }
+
+    #[test]
+    fn fold_clamped() {
+        let health = ClampedU8::<0, 101>::new(50).unwrap();
+        let deltas = [-30, -40, 25, 1000];
+        assert_eq!(
+            super::fold_clamped(health, deltas, OverflowMode::Saturating),
+            100
+        );
+        assert_eq!(
+            super::fold_clamped(health, [-30, -40, 25], OverflowMode::Saturating),
+            25
+        );
+        let dial = ClampedU8::<10, 20>::new(15).unwrap();
+        assert_eq!(
+            super::fold_clamped(dial, [7, -1], OverflowMode::Wrapping),
+            11
+        );
+        assert_eq!(super::fold_clamped(dial, [-6], OverflowMode::Wrapping), 19);
+        assert_eq!(
+            super::fold_clamped(dial, [i16::MIN, i16::MIN], OverflowMode::Wrapping),
+            19
+        );
+    }
+
+    #[test]
+    fn try_from_narrow_inner() {
+        let clamped = ClampedU16::<0, 200>::new(199).unwrap();
+        assert_eq!(u8::try_from(clamped).unwrap(), 199);
+        let clamped = ClampedU16From::<0>::new(256).unwrap();
+        assert!(u8::try_from(clamped).is_err());
+        let clamped = ClampedI32ToInclusive::<0>::new(-40_000).unwrap();
+        assert!(i16::try_from(clamped).is_err());
+    }
+
+    #[test]
+    fn clamp_to() {
+        let volume = ClampedU8Inclusive::<0, 100>::new(80).unwrap();
+        assert_eq!(volume.clamp_to(10, 50), 50);
+        assert_eq!(volume.clamp_to(90, 200), 90);
+        assert_eq!(volume.clamp_to(0, 255), 80);
+        assert_eq!(ClampedI8To::<0>::new(-100).unwrap().clamp_to(-50, 50), -50);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "no value in range"]
+    fn clamp_to_disjoint() {
+        ClampedU8Inclusive::<0, 100>::new(80)
+            .unwrap()
+            .clamp_to(101, 200);
+    }
+
+    #[test]
+    fn pack_two() {
+        type Mode = ClampedU8Inclusive<1, 5>;
+        type Level = ClampedI8<-8, 8>;
+        let mode = Mode::new(4).unwrap();
+        let level = Level::new(-2).unwrap();
+        let packed = super::pack_two(mode, level);
+        assert_eq!(packed, 3 | 6 << 3);
+        assert_eq!(
+            super::unpack_two::<Mode, Level>(packed),
+            Some((mode, level))
+        );
+        // mode offset 7 is out of bounds
+        assert_eq!(super::unpack_two::<Mode, Level>(7), None);
+        // there are bits set beyond those for level
+        assert_eq!(super::unpack_two::<Mode, Level>(1 << 7), None);
+
+        type Full = ClampedU8Inclusive<0, 255>;
+        let packed = super::pack_two(Full::new(0xAB).unwrap(), Full::new(0xCD).unwrap());
+        assert_eq!(packed, 0xCDAB);
+        assert_eq!(
+            super::unpack_two::<Full, Full>(packed),
+            Some((Full::new(0xAB).unwrap(), Full::new(0xCD).unwrap()))
+        );
+        assert_eq!(
+            ClampedU128Inclusive::<0, { u128::MAX }>::from_offset(u128::MAX).unwrap(),
+            u128::MAX
+        );
+    }
+
+    #[test]
+    fn sum_wide() {
+        let values = vec![ClampedU8Inclusive::<0, 255>::new(255).unwrap(); 1000];
+        assert_eq!(values.iter().sum::<u32>(), 255_000);
+        assert_eq!(values.into_iter().sum::<u32>(), 255_000);
+        let values = [ClampedI8To::<0>::new(-128).unwrap(); 3];
+        assert_eq!(values.iter().sum::<i32>(), -384);
+    }
+
+    #[test]
+    fn checked_rebound() {
+        let clamped = ClampedU8::<0, 100>::new(50).unwrap();
+        let wider: ClampedU8<0, 200> = clamped.checked_rebound().unwrap();
+        assert_eq!(wider, 50);
+        assert!(clamped.checked_rebound::<0, 50>().is_err());
+        assert_eq!(
+            ClampedI8To::<0>::new(-1)
+                .unwrap()
+                .checked_rebound::<10>()
+                .unwrap(),
+            -1
+        );
+    }
+
+    #[test]
+    fn inner_eq_clamped() {
+        let clamped = ClampedU8::<0, 10>::new(5).unwrap();
+        assert_eq!(5, clamped);
+        assert_ne!(6, clamped);
+        assert!(5 == ClampedI8To::<10>::new(5).unwrap());
+        assert!(5 == ClampedI8ToInclusive::<10>::new(5).unwrap());
+        assert!(5 == ClampedI8From::<0>::new(5).unwrap());
+        assert!(5 == ClampedI8Inclusive::<0, 5>::new(5).unwrap());
+    }
+
+    #[test]
+    fn is_subrange_of() {
+        assert!(ClampedU8::<10, 20>::is_subrange_of::<0, 20>());
+        assert!(!ClampedU8::<10, 20>::is_subrange_of::<11, 20>());
+        assert!(!ClampedU8::<10, 20>::is_subrange_of::<0, 19>());
+        assert!(ClampedI8Inclusive::<-5, 5>::is_subrange_of::<-5, 5>());
+        assert!(ClampedI8From::<0>::is_subrange_of::<-1>());
+        assert!(!ClampedI8To::<0>::is_subrange_of::<-1>());
+        const { assert!(ClampedU8ToInclusive::<9>::is_subrange_of::<10>()) };
+    }
+
+    #[test]
+    fn sort_key() {
+        struct Task {
+            name: &'static str,
+            priority: ClampedU8Inclusive<1, 5>,
+        }
+        let mut tasks = [(3, "b"), (1, "a"), (5, "c")].map(|(priority, name)| Task {
+            name,
+            priority: ClampedU8Inclusive::new(priority).unwrap(),
+        });
+        tasks.sort_by_key(|task| task.priority.sort_key());
+        assert_eq!(tasks.map(|task| task.name), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn from_digits() {
+        use std::num::IntErrorKind;
+
+        assert_eq!(ClampedU8::<10, 20>::from_digits([1, 5]).unwrap(), 15);
+        assert_eq!(ClampedU8::<0, 20>::from_digits([0, 0, 7]).unwrap(), 7);
+        assert!(matches!(
+            ClampedU8::<10, 20>::from_digits([2, 5]),
+            Err(ParseClampedError::OutOfBounds(_))
+        ));
+        fn kind<T, E>(res: Result<T, ParseClampedError<E>>) -> IntErrorKind {
+            match res {
+                Err(ParseClampedError::Parse(e)) => *e.kind(),
+                _ => panic!("expected a parse error"),
+            }
+        }
+        assert_eq!(
+            kind(ClampedU8::<0, 20>::from_digits([])),
+            IntErrorKind::Empty
+        );
+        assert_eq!(
+            kind(ClampedU8::<0, 20>::from_digits([1, 10])),
+            IntErrorKind::InvalidDigit
+        );
+        assert_eq!(
+            kind(ClampedU8::<0, 20>::from_digits([2, 5, 6])),
+            IntErrorKind::PosOverflow
+        );
+        assert_eq!(
+            kind(ClampedI128From::<0>::from_digits([9; 40])),
+            IntErrorKind::PosOverflow
+        );
+    }
+
+    #[test]
+    fn sub_inner() {
+        let clamped = ClampedU8::<10, 20>::new(15).unwrap();
+        assert_eq!((clamped - 5).unwrap(), 10);
+        assert!(matches!(clamped - 6, Err(ArithmeticError::OutOfBounds(_))));
+        // underflows the inner type
+        assert_eq!(
+            (clamped - 16).unwrap_err(),
+            ArithmeticError::Overflow { given: 15 }
+        );
+        let clamped = ClampedI8::<-128, 0>::new(-100).unwrap();
+        assert_eq!((clamped - 28).unwrap(), -128);
+        assert!((clamped - 29).is_err());
+    }
+
+    #[test]
+    fn cycle() {
+        let workers = ClampedU8::<0, 3>::cycle().take(7).collect::<Vec<_>>();
+        assert_eq!(workers, [0, 1, 2, 0, 1, 2, 0]);
+        assert_eq!(ClampedU8::<3, 3>::cycle().next(), None);
+        assert_eq!(ClampedI8To::<{ i8::MIN }>::cycle().next(), None);
+    }
+
+    #[test]
+    fn with_bounds() {
+        let positive = ClampedU8From::<1>::new(5).unwrap();
+        let bounded: ClampedU8<1, 10> = positive.with_upper().unwrap();
+        assert_eq!(bounded, 5);
+        assert!(positive.with_upper::<5>().is_err());
+
+        let small = ClampedI8To::<10>::new(-5).unwrap();
+        assert_eq!(small.with_lower::<-5>().unwrap(), -5);
+        assert!(small.with_lower::<0>().is_err());
+        let small = ClampedI8ToInclusive::<10>::new(10).unwrap();
+        assert_eq!(small.with_lower::<0>().unwrap(), 10);
+    }
+
+    #[test]
+    fn vec_ext() {
+        let mut values = Vec::<ClampedU8<0, 10>>::new();
+        values.push_raw(5u8).unwrap();
+        let _: OutOfBounds<u8> = values.push_raw(10u8).unwrap_err();
+        values.push_saturating(200u8);
+        assert_eq!(values, [5, 9]);
+    }
+
+    #[test]
+    fn overlaps_with() {
+        type Morning = ClampedU8<6, 12>;
+        assert!(!Morning::overlaps_with::<12, 18>());
+        assert!(!Morning::overlaps_with::<0, 6>());
+        assert!(Morning::overlaps_with::<11, 18>());
+        assert!(Morning::overlaps_with::<0, 24>());
+        assert!(!Morning::overlaps_with::<8, 8>());
+
+        type Shift = ClampedU8Inclusive<6, 12>;
+        assert!(Shift::overlaps_with::<12, 18>());
+        assert!(!Shift::overlaps_with::<13, 18>());
+        assert!(!Shift::overlaps_with::<10, 9>());
+    }
+
+    #[test]
+    #[cfg(feature = "precise-debug")]
+    fn precise_debug() {
+        let clamped = ClampedU8::<10, 20>::new(15).unwrap();
+        assert_eq!(format!("{clamped:?}"), "ClampedU8<10, 20>(15)");
+        let clamped = ClampedI8ToInclusive::<-1>::new(-5).unwrap();
+        assert_eq!(format!("{clamped:?}"), "ClampedI8ToInclusive<-1>(-5)");
+    }
+
+    #[test]
+    fn from_f32_saturating() {
         type Clamped = ClampedU8<10, 20>;
+        assert_eq!(Clamped::from_f32_saturating(12.5), 13);
+        assert_eq!(Clamped::from_f32_saturating(9.4), 10);
+        assert_eq!(Clamped::from_f32_saturating(-300.0), 10);
+        assert_eq!(Clamped::from_f32_saturating(19.4), 19);
+        assert_eq!(Clamped::from_f32_saturating(20.0), 19);
+        assert_eq!(Clamped::from_f32_saturating(f32::MAX), 19);
+        assert_eq!(Clamped::from_f32_saturating(f32::NAN), 10);
+        assert_eq!(ClampedI32Inclusive::<-5, 5>::from_f32_saturating(-5.5), -5);
+    }
 
-        Clamped::try_from(9).unwrap_err();
-        Clamped::try_from(10).unwrap();
-        Clamped::try_from(11).unwrap();
+    #[test]
+    fn union() {
+        let a = ClampedU8::<0, 8>::new(0b101).unwrap();
+        let b = ClampedU8::<0, 8>::new(0b011).unwrap();
+        assert_eq!(a.union_unchecked(b), 0b111);
+        assert_eq!(a.checked_union(b).unwrap(), 0b111);
 
-        Clamped::try_from(19).unwrap();
-        Clamped::try_from(20).unwrap_err();
-        Clamped::try_from(21).unwrap_err();
+        let a = ClampedU8::<0, 6>::new(0b101).unwrap();
+        let b = ClampedU8::<0, 6>::new(0b011).unwrap();
+        assert_eq!(a.checked_union(b), None);
+        assert_eq!(
+            ClampedI8Inclusive::<-1, 3>::new(-1)
+                .unwrap()
+                .checked_union(ClampedI8Inclusive::new(2).unwrap())
+                .unwrap(),
+            -1
+        );
+    }
 
-        type ClampedFrom = ClampedU8From<10>;
+    #[test]
+    fn try_from_64() {
+        assert_eq!(ClampedU8::<0, 10>::try_from_u64(5).unwrap(), 5);
+        assert!(matches!(
+            ClampedU8::<0, 10>::try_from_u64(256),
+            Err(NarrowError::DoesNotFit(_))
+        ));
+        assert!(matches!(
+            ClampedU8::<0, 10>::try_from_u64(10),
+            Err(NarrowError::OutOfBounds(_))
+        ));
+        assert_eq!(ClampedI16To::<0>::try_from_i64(-5).unwrap(), -5);
+        assert!(matches!(
+            ClampedI32From::<0>::try_from_i64(i64::MIN),
+            Err(NarrowError::DoesNotFit(_))
+        ));
+    }
 
-        ClampedFrom::try_from(9).unwrap_err();
-        ClampedFrom::try_from(10).unwrap();
-        ClampedFrom::try_from(11).unwrap();
+    #[test]
+    fn rank_percentile() {
+        let samples = [15, 20, 35, 40, 50].map(|it| ClampedU8::<0, 100>::new(it).unwrap());
+        assert_eq!(super::rank(ClampedU8::new(35).unwrap(), &samples), 3);
+        assert_eq!(super::rank(ClampedU8::new(0).unwrap(), &samples), 0);
 
-        type ClampedInclusive = ClampedU8Inclusive<10, 20>;
+        assert_eq!(super::percentile(5.0, &samples).unwrap(), 15);
+        assert_eq!(super::percentile(30.0, &samples).unwrap(), 20);
+        assert_eq!(super::percentile(40.0, &samples).unwrap(), 20);
+        assert_eq!(super::percentile(50.0, &samples).unwrap(), 35);
+        assert_eq!(super::percentile(100.0, &samples).unwrap(), 50);
+        assert_eq!(super::percentile(0.0, &samples).unwrap(), 15);
+        assert_eq!(super::percentile(101.0, &samples), None);
+        assert_eq!(super::percentile(f64::NAN, &samples), None);
+        assert_eq!(super::percentile::<0, 100>(50.0, &[]), None);
+    }
 
-        ClampedInclusive::try_from(9).unwrap_err();
-        ClampedInclusive::try_from(10).unwrap();
-        ClampedInclusive::try_from(11).unwrap();
+    #[test]
+    fn to_inclusive() {
+        let clamped = ClampedU8::<0, 100>::new(99).unwrap();
+        let inclusive: ClampedU8Inclusive<0, 99> = clamped.to_inclusive().unwrap();
+        assert_eq!(inclusive, 99);
+        assert!(clamped.to_inclusive::<98>().is_err());
+    }
 
-        ClampedInclusive::try_from(19).unwrap();
-        ClampedInclusive::try_from(20).unwrap();
-        ClampedInclusive::try_from(21).unwrap_err();
+    #[test]
+    #[cfg(feature = "rand")]
+    fn sample_triangular() {
+        use rand::SeedableRng as _;
 
-        type ClampedTo = ClampedU8To<10>;
+        type Difficulty = ClampedU8Inclusive<1, 9>;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mode = Difficulty::new(5).unwrap();
+        let mut counts = [0; 10];
+        for _ in 0..10_000 {
+            counts[usize::from(Difficulty::sample_triangular(&mut rng, mode).get())] += 1;
+        }
+        assert_eq!(counts[0], 0);
+        assert!(counts[5] > counts[3] && counts[3] > counts[1]);
+        assert!(counts[5] > counts[7] && counts[7] > counts[9]);
 
-        ClampedTo::try_from(9).unwrap();
-        ClampedTo::try_from(10).unwrap_err();
-        ClampedTo::try_from(11).unwrap_err();
+        let edge = ClampedI8::<-10, 10>::new(-10).unwrap();
+        for _ in 0..100 {
+            let sample = ClampedI8::sample_triangular(&mut rng, edge);
+            assert!((-10..10).contains(&sample.get()));
+        }
+    }
 
-        type ClampedToInclusive = ClampedU8ToInclusive<10>;
+    #[test]
+    fn div_inner() {
+        let clamped = ClampedU8::<10, 100>::new(60).unwrap();
+        assert_eq!((clamped / 3).unwrap(), 20);
+        assert!((clamped / 7).is_err());
+        let clamped = ClampedI8::<{ i8::MIN }, 0>::new(i8::MIN).unwrap();
+        assert_eq!((clamped / 2).unwrap(), -64);
+        assert_eq!(
+            (clamped / -1).unwrap_err(),
+            DivError::Overflow { given: i8::MIN }
+        );
+        assert!(matches!(
+            ClampedI8::<{ i8::MIN }, 0>::new(-2).unwrap() / -1,
+            Err(DivError::OutOfBounds(_))
+        ));
+    }
 
-        ClampedToInclusive::try_from(9).unwrap();
-        ClampedToInclusive::try_from(10).unwrap();
-        ClampedToInclusive::try_from(11).unwrap_err();
+    #[test]
+    fn div_inner_zero() {
+        let clamped = ClampedU8::<10, 100>::new(60).unwrap();
+        assert_eq!((clamped / 0).unwrap_err(), DivError::DivideByZero);
+    }
+
+    #[test]
+    fn rebound_to() {
+        let small = ClampedU8To::<200>::new(150).unwrap();
+        let wide: ClampedU16To<1000> = small.rebound_to().unwrap();
+        assert_eq!(wide, 150);
+        assert!(small.rebound_to::<100>().is_err());
+        let small = ClampedI32ToInclusive::<0>::new(-5).unwrap();
+        assert_eq!(small.rebound_to::<{ i64::MAX }>().unwrap(), -5);
+    }
+
+    #[test]
+    fn default_to() {
+        #[derive(Default)]
+        struct Counters {
+            count: ClampedU8To<10>,
+            index: ClampedUsizeToInclusive<0>,
+            offset: ClampedI8To<0>,
+        }
+        let counters = Counters::default();
+        assert_eq!(counters.count, 0);
+        assert_eq!(counters.index, 0);
+        assert_eq!(counters.offset, i8::MIN);
+        assert_eq!(ClampedI8ToInclusive::<{ i8::MIN }>::default(), i8::MIN);
+    }
+
+    #[test]
+    fn product_wide() {
+        let values = vec![ClampedU8Inclusive::<0, 255>::new(255).unwrap(); 8];
+        assert_eq!(values.iter().product::<u64>(), 255u64.pow(8));
+        assert_eq!(values.into_iter().product::<u64>(), 255u64.pow(8));
+        let values = [ClampedI8To::<0>::new(-128).unwrap(); 3];
+        assert_eq!(values.iter().product::<i64>(), -(128i64.pow(3)));
+    }
+
+    #[test]
+    fn from_str_auto_radix() {
+        type Register = ClampedU8Inclusive<0, 255>;
+        assert_eq!(Register::from_str_auto_radix("0xFF").unwrap(), 255);
+        assert_eq!(Register::from_str_auto_radix("0o17").unwrap(), 15);
+        assert_eq!(Register::from_str_auto_radix("0b1010").unwrap(), 10);
+        assert_eq!(Register::from_str_auto_radix("42").unwrap(), 42);
+        assert_eq!(Register::from_str_auto_radix("+0x10").unwrap(), 16);
+        assert_eq!(Register::from_str_auto_radix("0").unwrap(), 0);
+        for invalid in ["", "0x", "0x-1", "0x+1", "0xG", "0b2", "-1", "0x100"] {
+            assert!(
+                matches!(
+                    Register::from_str_auto_radix(invalid),
+                    Err(ParseClampedError::Parse(_))
+                ),
+                "{invalid:?}"
+            );
+        }
+        assert!(matches!(
+            ClampedU8::<0, 16>::from_str_auto_radix("0x10"),
+            Err(ParseClampedError::OutOfBounds(_))
+        ));
+        assert_eq!(
+            ClampedI8From::<-20>::from_str_auto_radix("-0x10").unwrap(),
+            -16
+        );
+        assert_eq!(
+            ClampedI8To::<0>::from_str_auto_radix("-0x80").unwrap(),
+            -128
+        );
+    }
+
+    #[test]
+    fn wrapping_newtype() {
+        let mut hour = Wrapping(ClampedU8::<0, 24>::new(22).unwrap());
+        hour += 3;
+        assert_eq!(*hour, 1);
+        assert_eq!((hour - 2).0, 23);
+        hour -= 50;
+        assert_eq!(hour.get(), 23);
+        let hour: ClampedU8<0, 24> = hour.into();
+        assert_eq!(hour, 23);
+
+        let degrees = Wrapping::from(ClampedI16Inclusive::<-180, 179>::new(170).unwrap());
+        assert_eq!(*(degrees + 20), -170);
+    }
+
+    #[test]
+    fn try_from_char() {
+        type Printable = ClampedU8Inclusive<0x20, 0x7E>;
+        assert_eq!(Printable::try_from('A').unwrap(), b'A');
+        assert!(matches!(
+            Printable::try_from('\n'),
+            Err(FromCharError::OutOfBounds(_))
+        ));
+        assert_eq!(ClampedU8::<0, 255>::try_from('é').unwrap(), 0xE9);
+        assert!(matches!(
+            ClampedU8From::<0>::try_from('€'),
+            Err(FromCharError::NotLatin1('€'))
+        ));
+    }
+
+    #[test]
+    fn range_query() {
+        let mut map = collections::BTreeMap::new();
+        for code in [404, 200, 500] {
+            map.insert(
+                ClampedU16Inclusive::<100, 599>::new(code).unwrap(),
+                code / 100,
+            );
+        }
+        let classes = ClampedU16Inclusive::range_query(&map)
+            .map(|(_, class)| *class)
+            .collect::<Vec<_>>();
+        assert_eq!(classes, [2, 4, 5]);
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn to_grouped_string() {
+        let count = ClampedU64::<0, 1_000_000_001>::new(1_000_000_000).unwrap();
+        assert_eq!(count.to_grouped_string(','), "1,000,000,000");
+        let count = ClampedU64::<0, 1_000_000_001>::new(999).unwrap();
+        assert_eq!(count.to_grouped_string(','), "999");
+        let count = ClampedU64::<0, 1_000_000_001>::new(1234).unwrap();
+        assert_eq!(count.to_grouped_string('_'), "1_234");
+        let delta = ClampedI32To::<0>::new(-123_456).unwrap();
+        assert_eq!(delta.to_grouped_string(' '), "-123 456");
+    }
+
+    #[test]
+    fn lookup_table() {
+        type Digit = ClampedU8Inclusive<0, 9>;
+        let mut squares = LookupTable::<Digit, u8, 10>::new(|digit| digit.get() * digit.get());
+        assert_eq!(squares[Digit::new(7).unwrap()], 49);
+        squares[Digit::new(0).unwrap()] = 100;
+        assert_eq!(squares.as_slice()[..3], [100, 1, 4]);
+
+        type Offset = ClampedI8<-2, 2>;
+        let names = LookupTable::<Offset, &str, 4>::new(|offset| match offset.get() {
+            ..=-1 => "negative",
+            _ => "non-negative",
+        });
+        assert_eq!(names[Offset::new(-2).unwrap()], "negative");
+        assert_eq!(names[Offset::new(1).unwrap()], "non-negative");
+    }
+
+    #[test]
+    #[should_panic = "N must be the number of values in the range"]
+    fn lookup_table_wrong_size() {
+        LookupTable::<ClampedU8<0, 10>, (), 9>::new(|_| ());
+    }
+
+    #[test]
+    fn try_from_str() {
+        fn build<T: for<'a> TryFrom<&'a str>>(s: &str) -> Option<T> {
+            T::try_from(s).ok()
+        }
+        assert_eq!(build::<ClampedU8<0, 10>>("5").unwrap(), 5);
+        assert!(build::<ClampedU8<0, 10>>("10").is_none());
+        assert!(matches!(
+            ClampedI8To::<0>::try_from("x"),
+            Err(ParseClampedError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn mirror() {
+        let it = ClampedU8::<10, 20>::new(10).unwrap();
+        assert_eq!(it.mirror(), 19);
+        assert_eq!(it.mirror().mirror(), it);
+        assert_eq!(ClampedU8Inclusive::<10, 20>::new(15).unwrap().mirror(), 15);
+        assert_eq!(
+            ClampedI8Inclusive::<-128, 127>::new(127).unwrap().mirror(),
+            -128
+        );
+        assert_eq!(
+            ClampedI8Inclusive::<-128, 127>::new(0).unwrap().mirror(),
+            -1
+        );
+    }
+
+    #[test]
+    fn duration() {
+        use std::time::Duration;
+        type Timeout = ClampedU32<1, 3600>;
+        let it = Timeout::try_from_secs(Duration::from_millis(1500)).unwrap();
+        assert_eq!(it, 1);
+        assert_eq!(it.to_duration(), Duration::from_secs(1));
+        assert!(Timeout::try_from_secs(Duration::ZERO).is_err());
+        assert!(Timeout::try_from_secs(Duration::from_secs(3600)).is_err());
+        assert!(
+            ClampedU32::<0, { u32::MAX }>::try_from_secs(Duration::from_secs(u64::MAX)).is_err()
+        );
+        assert_eq!(
+            ClampedU64::<0, 10>::try_from_secs(Duration::from_secs(9))
+                .unwrap()
+                .to_duration(),
+            Duration::from_secs(9)
+        );
+    }
+
+    #[test]
+    fn collect_saturating() {
+        let it: Vec<ClampedU8<10, 20>> = super::collect_saturating([0_u8, 15, 255]);
+        assert_eq!(it, [10, 15, 19]);
+        let it = super::collect_saturating::<i16, ClampedI16From<0>>(vec![-1, 1]);
+        assert_eq!(it, [0, 1]);
+    }
+
+    #[test]
+    fn rem_self() {
+        let position = ClampedU8::<0, 100>::new(42).unwrap();
+        assert_eq!(position % ClampedU8::new(10).unwrap(), 2);
+        assert_eq!(position % 5, 2);
+    }
+
+    #[test]
+    #[should_panic = "attempt to calculate the remainder with a divisor of zero"]
+    fn rem_self_zero() {
+        let _ = ClampedU8::<0, 100>::new(42).unwrap() % ClampedU8::new(0).unwrap();
+    }
+
+    #[test]
+    #[should_panic = "attempt to calculate the remainder with overflow"]
+    fn rem_self_overflow() {
+        type Clamped = ClampedI8<{ i8::MIN }, 0>;
+        let _ = Clamped::new(i8::MIN).unwrap() % Clamped::new(-1).unwrap();
+    }
+
+    #[test]
+    fn midpoint_with() {
+        let (lo, hi) = (
+            ClampedU8::<0, 255>::new(0).unwrap(),
+            ClampedU8::<0, 255>::new(254).unwrap(),
+        );
+        assert_eq!(lo.midpoint_with(hi), 127);
+        assert_eq!(hi.midpoint_with(lo), 127);
+        let (lo, hi) = (
+            ClampedI8Inclusive::<-128, 127>::new(-128).unwrap(),
+            ClampedI8Inclusive::<-128, 127>::new(127).unwrap(),
+        );
+        assert_eq!(lo.midpoint_with(hi), -1);
+        assert_eq!(hi.midpoint_with(lo), 0);
+        assert_eq!(hi.midpoint_with(hi), 127);
+    }
+
+    #[test]
+    fn join_display() {
+        assert_eq!(ClampedI8To::<0>::new(-5).unwrap().to_string(), "-5");
+        assert_eq!(format!("{:>3}", ClampedU8::<0, 10>::new(7).unwrap()), "  7");
+        let values = ClampedU8Inclusive::<1, 3>::iter().collect::<Vec<_>>();
+        assert_eq!(super::join_display(&values, ", "), "1, 2, 3");
+        assert_eq!(super::join_display::<ClampedU8<0, 1>>(&[], ", "), "");
+    }
+
+    #[test]
+    fn as_ref() {
+        fn inner<T: AsRef<u16>>(value: &T) -> u16 {
+            *value.as_ref()
+        }
+        assert_eq!(inner(&ClampedU16::<0, 10>::new(5).unwrap()), 5);
+        assert_eq!(inner(&ClampedU16ToInclusive::<10>::new(10).unwrap()), 10);
+        assert_eq!(*ClampedI8From::<-5>::new(-5).unwrap().as_ref(), -5);
     }
 }